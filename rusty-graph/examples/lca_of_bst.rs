@@ -0,0 +1,32 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rusty_graph::problems::leetcode::lca::_235_lca_of_bst::lowest_common_ancestor_iterative;
+use rusty_graph::problems::leetcode::trees::TreeNode;
+
+fn main() {
+    // Example BST: [6,2,8,0,4,7,9,null,null,3,5]
+    let root = Some(Rc::new(RefCell::new(TreeNode {
+        val: 6,
+        left: Some(Rc::new(RefCell::new(TreeNode {
+            val: 2,
+            left: Some(Rc::new(RefCell::new(TreeNode::new(0)))),
+            right: Some(Rc::new(RefCell::new(TreeNode {
+                val: 4,
+                left: Some(Rc::new(RefCell::new(TreeNode::new(3)))),
+                right: Some(Rc::new(RefCell::new(TreeNode::new(5)))),
+            }))),
+        }))),
+        right: Some(Rc::new(RefCell::new(TreeNode {
+            val: 8,
+            left: Some(Rc::new(RefCell::new(TreeNode::new(7)))),
+            right: Some(Rc::new(RefCell::new(TreeNode::new(9)))),
+        }))),
+    })));
+
+    let p = Some(Rc::new(RefCell::new(TreeNode::new(2)))); // Node 2
+    let q = Some(Rc::new(RefCell::new(TreeNode::new(8)))); // Node 8
+
+    let lca = lowest_common_ancestor_iterative(root.clone(), p, q);
+    println!("LCA: {}", lca.unwrap().borrow().val); // Output: 6
+}
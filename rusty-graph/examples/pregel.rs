@@ -0,0 +1,129 @@
+use rusty_graph::parallel::{Aggregator, Context, Pregel, VertexProgram};
+use rusty_graph::representations::{Graph, GraphBuilder};
+
+/// Single-source shortest paths: a vertex that learns a shorter distance
+/// passes it on along its edges, then sleeps until something better arrives
+struct ShortestPaths {
+    source: &'static str,
+}
+
+impl VertexProgram<&'static str, u32> for ShortestPaths {
+    type Value = Option<u32>;
+    type Message = u32;
+
+    fn initial(&self, _vertex: &&'static str) -> Self::Value {
+        None
+    }
+
+    fn compute(&self, context: &mut Context<'_, &'static str, u32, Self::Value, u32>, messages: Vec<u32>) {
+        let start = (context.superstep() == 0 && *context.vertex() == self.source).then_some(0);
+        let candidate = messages.into_iter().chain(start).min();
+
+        if let Some(distance) = candidate
+            && context.value().is_none_or(|current| distance < current)
+        {
+            *context.value_mut() = Some(distance);
+            let offers: Vec<_> = context.edges().map(|(to, w)| (to, distance + w)).collect();
+            for (to, offer) in offers {
+                context.send(to, offer);
+            }
+        }
+        context.vote_to_halt();
+    }
+}
+
+/// PageRank for a fixed number of supersteps, with the rank of dangling
+/// vertices collected by a sum aggregator and spread evenly
+struct PageRank {
+    damping: f64,
+    supersteps: usize,
+}
+
+impl VertexProgram<&'static str, u32> for PageRank {
+    type Value = f64;
+    type Message = f64;
+
+    fn initial(&self, _vertex: &&'static str) -> f64 {
+        0.0
+    }
+
+    fn compute(&self, context: &mut Context<'_, &'static str, u32, f64, f64>, messages: Vec<f64>) {
+        let n = context.vertex_count() as f64;
+        *context.value_mut() = if context.superstep() == 0 {
+            1.0 / n
+        } else {
+            let dangling = context.aggregated("dangling").unwrap_or(0.0);
+            (1.0 - self.damping) / n + self.damping * (messages.iter().sum::<f64>() + dangling / n)
+        };
+
+        if context.superstep() == self.supersteps {
+            context.vote_to_halt();
+        } else if context.out_degree() == 0 {
+            let rank = *context.value();
+            context.aggregate("dangling", rank);
+        } else {
+            let share = context.value() / context.out_degree() as f64;
+            context.send_to_neighbors(share);
+        }
+    }
+}
+
+/// Connected components by label propagation: every vertex adopts the
+/// smallest label it hears of, starting from its own id
+struct LabelPropagation;
+
+impl VertexProgram<&'static str, u32> for LabelPropagation {
+    type Value = usize;
+    type Message = usize;
+
+    fn initial(&self, _vertex: &&'static str) -> usize {
+        usize::MAX
+    }
+
+    fn compute(&self, context: &mut Context<'_, &'static str, u32, usize, usize>, messages: Vec<usize>) {
+        let own = (context.superstep() == 0).then_some(context.id());
+        let smallest = messages.into_iter().chain(own).min().unwrap();
+        if smallest < *context.value() {
+            *context.value_mut() = smallest;
+            context.aggregate("changed", 1.0);
+            context.send_to_neighbors(smallest);
+        }
+        context.vote_to_halt();
+    }
+}
+
+fn main() {
+    let roads: Graph<&str, u32> = GraphBuilder::new()
+        .directed()
+        .add_edge("A", "B", 4)
+        .add_edge("A", "C", 1)
+        .add_edge("C", "B", 2)
+        .add_edge("B", "D", 5)
+        .add_edge("C", "D", 8)
+        .add_edge("E", "F", 3)
+        .build();
+
+    let distances = Pregel::new(&roads, ShortestPaths { source: "A" })
+        .combiner(u32::min)
+        .workers(2)
+        .run();
+    println!("Distances from A: {:?}", distances.values); // B: 3, C: 1, D: 8, E and F unreachable
+
+    let ranks = Pregel::new(&roads, PageRank { damping: 0.85, supersteps: 30 })
+        .combiner(|a, b| a + b)
+        .aggregator("dangling", Aggregator::Sum)
+        .run();
+    println!("PageRank: {:?}", ranks.values);
+
+    let undirected: Graph<&str, u32> = GraphBuilder::new()
+        .undirected()
+        .add_edge("A", "B", 1)
+        .add_edge("B", "C", 1)
+        .add_edge("D", "E", 1)
+        .build();
+    let components = Pregel::new(&undirected, LabelPropagation)
+        .combiner(usize::min)
+        .aggregator("changed", Aggregator::Sum)
+        .run();
+    println!("Component labels after {} supersteps: {:?}", components.supersteps, components.values);
+}
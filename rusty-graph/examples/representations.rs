@@ -0,0 +1,48 @@
+use rusty_graph::representations::directed_graph::DirectedGraph;
+use rusty_graph::representations::edge_list::EdgeListGraph;
+use rusty_graph::representations::matrix_graph::MatrixGraph;
+use rusty_graph::representations::undirected_graph::UndirectedGraph;
+use rusty_graph::representations::weighted_graph::WeightedGraph;
+
+fn main() {
+    let mut graph = DirectedGraph::new();
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 2);
+    graph.add_edge(2, 0);
+    graph.add_edge(2, 3);
+
+    println!("{:?}", graph);
+
+    let mut graph = UndirectedGraph::new();
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 2);
+    graph.add_edge(2, 3);
+
+    println!("{:?}", graph);
+
+    let mut graph = WeightedGraph::new();
+    graph.add_edge(0, 1, 4);
+    graph.add_edge(0, 2, 1);
+    graph.add_edge(1, 2, 2);
+    graph.add_edge(2, 3, 5);
+
+    println!("{:?}", graph);
+
+    let mut graph = MatrixGraph::new(4);
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 2);
+    graph.add_edge(2, 3);
+
+    println!("{:?}", graph);
+
+    let mut graph = EdgeListGraph::new();
+    graph.add_edge(0, 1);
+    graph.add_edge(0, 2);
+    graph.add_edge(1, 2);
+    graph.add_edge(2, 3);
+
+    println!("{:?}", graph);
+}
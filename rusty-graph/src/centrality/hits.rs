@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, Weight};
+
+/// Settings for HITS
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitsOptions {
+    /// Stop once hub and authority scores change by less than this in total (L1 norm)
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for HitsOptions {
+    fn default() -> Self {
+        Self { tolerance: 1e-8, max_iterations: 100 }
+    }
+}
+
+/// Hub and authority score of every vertex, each summing to 1
+#[derive(Debug, Clone)]
+pub struct Hits<V> {
+    /// High for vertices pointing to good authorities
+    pub hubs: HashMap<V, f64>,
+    /// High for vertices pointed to by good hubs
+    pub authorities: HashMap<V, f64>,
+}
+
+/// Kleinberg's HITS by power iteration, O(E) per round: a vertex's authority
+/// is the sum of the hub scores pointing to it, its hub score the sum of the
+/// authorities it points to, both renormalized every round.
+///
+/// Parallel edges count separately and undirected edges both ways. Scores
+/// stay zero when the graph has no edges.
+pub fn hits<V, E>(graph: &Graph<V, E>, options: HitsOptions) -> Hits<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let csr = graph.freeze();
+    let n = csr.vertex_count();
+    let mut hubs = vec![1.0 / n as f64; n];
+    let mut authorities = vec![0.0; n];
+
+    for _ in 0..options.max_iterations {
+        let next_authorities =
+            normalized((0..n).map(|v| total(csr.in_neighbors(v).map(|(u, _)| hubs[u]))).collect());
+        let next_hubs =
+            normalized((0..n).map(|u| total(csr.neighbors(u).map(|(v, _)| next_authorities[v]))).collect());
+
+        let change: f64 = next_hubs.iter().zip(&hubs)
+            .chain(next_authorities.iter().zip(&authorities))
+            .map(|(a, b)| (a - b).abs())
+            .sum();
+        hubs = next_hubs;
+        authorities = next_authorities;
+        if change < options.tolerance {
+            break;
+        }
+    }
+
+    let vertices = csr.vertices();
+    Hits {
+        hubs: vertices.iter().cloned().zip(hubs).collect(),
+        authorities: vertices.iter().cloned().zip(authorities).collect(),
+    }
+}
+
+/// Sum starting from +0.0, so vertices without edges score a plain zero
+fn total(scores: impl Iterator<Item = f64>) -> f64 {
+    scores.fold(0.0, |sum, score| sum + score)
+}
+
+/// Scale scores to sum to 1, leaving all-zero scores alone
+fn normalized(mut scores: Vec<f64>) -> Vec<f64> {
+    let sum = total(scores.iter().copied());
+    if sum > 0.0 {
+        for score in &mut scores {
+            *score /= sum;
+        }
+    }
+    scores
+}
@@ -0,0 +1,5 @@
+pub mod pagerank;
+pub mod hits;
+
+pub use hits::{Hits, HitsOptions, hits};
+pub use pagerank::{PageRankOptions, pagerank, personalized_pagerank};
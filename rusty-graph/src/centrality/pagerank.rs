@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{CsrGraph, Graph, VertexId, Weight};
+
+/// Settings for PageRank
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageRankOptions {
+    /// Probability of following an edge rather than jumping to a random vertex
+    pub damping: f64,
+    /// Stop once the ranks change by less than this in total (L1 norm)
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for PageRankOptions {
+    fn default() -> Self {
+        Self { damping: 0.85, tolerance: 1e-6, max_iterations: 100 }
+    }
+}
+
+/// PageRank of every vertex, summing to 1.
+///
+/// Runs the same power iteration as `parallel::pagerank` on a frozen
+/// snapshot: damping `d`, rank of dangling vertices spread over all vertices,
+/// stop once the total change drops below the tolerance. Parallel edges count
+/// separately and undirected edges both ways.
+pub fn pagerank<V, E>(graph: &Graph<V, E>, options: PageRankOptions) -> HashMap<V, f64>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    with_teleport(graph, options, uniform(graph.vertex_count()))
+}
+
+/// PageRank personalized to a seed set: random jumps and the rank of
+/// dangling vertices land on the seeds only, evenly, so scores measure
+/// closeness to the seeds. Seeds missing from the graph are ignored; `None`
+/// if no seed is in the graph.
+pub fn personalized_pagerank<V, E>(graph: &Graph<V, E>, seeds: &[V], options: PageRankOptions) -> Option<HashMap<V, f64>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let seeds: HashSet<_> = seeds.iter().filter_map(|seed| graph.vertex_id(seed)).collect();
+    if seeds.is_empty() {
+        return None;
+    }
+
+    let mut teleport = vec![0.0; graph.vertex_count()];
+    for &seed in &seeds {
+        teleport[seed] = 1.0 / seeds.len() as f64;
+    }
+    Some(with_teleport(graph, options, teleport))
+}
+
+fn with_teleport<V, E>(graph: &Graph<V, E>, options: PageRankOptions, teleport: Vec<f64>) -> HashMap<V, f64>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let ranks = pagerank_with_teleport(&graph.freeze(), options, &teleport);
+    graph.vertices().cloned().zip(ranks).collect()
+}
+
+/// PageRank where random jumps, and the rank of dangling vertices, land
+/// according to `teleport`, a distribution over the vertices. Uniform
+/// teleport gives plain PageRank.
+pub(crate) fn pagerank_with_teleport<V, E>(graph: &CsrGraph<V, E>, options: PageRankOptions, teleport: &[f64]) -> Vec<f64>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    power_iteration(graph, options, teleport, |share, dangling| {
+        (0..graph.vertex_count()).map(|v| pull(graph, options, teleport, share, dangling, v)).collect()
+    })
+}
+
+pub(crate) fn uniform(n: usize) -> Vec<f64> {
+    vec![1.0 / n as f64; n]
+}
+
+/// Shared iteration loop from the teleport distribution; `step` maps every
+/// vertex's outgoing share and the dangling mass to the next ranks. Sums over
+/// all vertices stay sequential, since a parallel reduction could add floats
+/// in a different order.
+pub(crate) fn power_iteration<V, E, F>(graph: &CsrGraph<V, E>, options: PageRankOptions, teleport: &[f64], step: F) -> Vec<f64>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+    F: Fn(&[f64], f64) -> Vec<f64>,
+{
+    let n = graph.vertex_count();
+    let mut ranks = teleport.to_vec();
+
+    for _ in 0..options.max_iterations {
+        let share: Vec<f64> = (0..n)
+            .map(|u| match graph.out_degree(u) {
+                0 => 0.0,
+                degree => ranks[u] / degree as f64,
+            })
+            .collect();
+        let dangling: f64 = (0..n).filter(|&u| graph.out_degree(u) == 0).map(|u| ranks[u]).sum();
+
+        let next = step(&share, dangling);
+        let change: f64 = next.iter().zip(&ranks).map(|(a, b)| (a - b).abs()).sum();
+        ranks = next;
+        if change < options.tolerance {
+            break;
+        }
+    }
+
+    ranks
+}
+
+pub(crate) fn pull<V, E>(
+    graph: &CsrGraph<V, E>,
+    options: PageRankOptions,
+    teleport: &[f64],
+    share: &[f64],
+    dangling: f64,
+    v: VertexId,
+) -> f64
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let incoming: f64 = graph.in_neighbors(v).map(|(u, _)| share[u]).sum();
+    (1.0 - options.damping) * teleport[v] + options.damping * (incoming + dangling * teleport[v])
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::fmt::Debug;
+
+use super::generic_graph::{EdgeDirection, VertexId, Weight};
+use super::graph_view::GraphView;
+
+/// Compressed sparse row (CSR) graph
+///
+/// A read-only snapshot produced by `Graph::freeze()`. Outgoing edges of
+/// vertex `v` are stored contiguously in `targets[offsets[v]..offsets[v + 1]]`
+/// with their weights at the same positions, so neighbor iteration walks flat
+/// arrays instead of chasing per-vertex allocations. A reverse CSR indexes the
+/// same edges by their target for in-edge queries.
+#[derive(Debug, Clone)]
+pub struct CsrGraph<V, E = ()>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    vertices: Vec<V>,
+    vertex_ids: HashMap<V, VertexId>,
+    direction: EdgeDirection,
+    offsets: Vec<usize>,
+    targets: Vec<VertexId>,
+    weights: Vec<E>,
+    in_offsets: Vec<usize>,
+    sources: Vec<VertexId>,
+    in_edges: Vec<usize>, // Position of each in-edge in `targets`/`weights`
+}
+
+impl<V, E> CsrGraph<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    /// Build the CSR arrays with a counting sort over the edge sources.
+    /// Edges keep their relative insertion order within each row.
+    pub(crate) fn new(
+        vertices: Vec<V>,
+        vertex_ids: HashMap<V, VertexId>,
+        direction: EdgeDirection,
+        edges: &[(VertexId, VertexId, E)],
+    ) -> Self {
+        let n = vertices.len();
+        let m = edges.len();
+
+        let mut offsets = vec![0; n + 1];
+        let mut in_offsets = vec![0; n + 1];
+        for &(from, to, _) in edges {
+            offsets[from + 1] += 1;
+            in_offsets[to + 1] += 1;
+        }
+        for v in 0..n {
+            offsets[v + 1] += offsets[v];
+            in_offsets[v + 1] += in_offsets[v];
+        }
+
+        // Place every edge in its source row
+        let mut next = offsets.clone();
+        let mut targets = vec![0; m];
+        let mut weights = vec![E::default(); m];
+        for (from, to, weight) in edges {
+            targets[next[*from]] = *to;
+            weights[next[*from]] = weight.clone();
+            next[*from] += 1;
+        }
+
+        // Index the placed edges by their target
+        let mut next = in_offsets.clone();
+        let mut sources = vec![0; m];
+        let mut in_edges = vec![0; m];
+        for from in 0..n {
+            let row = offsets[from]..offsets[from + 1];
+            for (slot, &to) in row.clone().zip(&targets[row]) {
+                sources[next[to]] = from;
+                in_edges[next[to]] = slot;
+                next[to] += 1;
+            }
+        }
+
+        CsrGraph {
+            vertices,
+            vertex_ids,
+            direction,
+            offsets,
+            targets,
+            weights,
+            in_offsets,
+            sources,
+            in_edges,
+        }
+    }
+
+    /// Get the id of a vertex
+    pub fn vertex_id(&self, vertex: &V) -> Option<VertexId> {
+        self.vertex_ids.get(vertex).copied()
+    }
+
+    /// Get the vertex stored under an id
+    pub fn vertex(&self, id: VertexId) -> Option<&V> {
+        self.vertices.get(id)
+    }
+
+    /// Get all vertices, indexed by id
+    pub fn vertices(&self) -> &[V] {
+        &self.vertices
+    }
+
+    /// Get the direction of the graph this snapshot was taken from
+    pub fn direction(&self) -> EdgeDirection {
+        self.direction
+    }
+
+    /// Iterate over `(target, weight)` of the outgoing edges of a vertex
+    pub fn neighbors(&self, id: VertexId) -> impl Iterator<Item = (VertexId, &E)> {
+        let range = self.offsets[id]..self.offsets[id + 1];
+        self.targets[range.clone()].iter().copied().zip(&self.weights[range])
+    }
+
+    /// Iterate over `(source, weight)` of the incoming edges of a vertex
+    pub fn in_neighbors(&self, id: VertexId) -> impl Iterator<Item = (VertexId, &E)> {
+        let range = self.in_offsets[id]..self.in_offsets[id + 1];
+        self.sources[range.clone()].iter()
+            .copied()
+            .zip(&self.in_edges[range])
+            .map(|(from, &slot)| (from, &self.weights[slot]))
+    }
+
+    /// Get the number of outgoing edges of a vertex
+    pub fn out_degree(&self, id: VertexId) -> usize {
+        self.offsets[id + 1] - self.offsets[id]
+    }
+
+    /// Get the number of incoming edges of a vertex
+    pub fn in_degree(&self, id: VertexId) -> usize {
+        self.in_offsets[id + 1] - self.in_offsets[id]
+    }
+
+    /// Check if an edge exists between two vertex ids
+    pub fn has_edge(&self, from: VertexId, to: VertexId) -> bool {
+        self.neighbors(from).any(|(v, _)| v == to)
+    }
+
+    /// Get the number of vertices
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Get the number of edges
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+}
+
+impl<V, E> GraphView for CsrGraph<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    type Weight = E;
+
+    fn node_count(&self) -> usize {
+        self.vertex_count()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.direction == EdgeDirection::Directed
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, E)> {
+        self.neighbors(node).map(|(to, w)| (to, w.clone()))
+    }
+}
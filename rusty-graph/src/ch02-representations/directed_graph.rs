@@ -1,29 +1,40 @@
 use std::collections::HashMap;
 
-#[derive(Debug)]
-struct DirectedGraph {
+use super::generic_graph::VertexId;
+use super::graph_view::GraphView;
+
+#[derive(Debug, Default)]
+pub struct DirectedGraph {
     adjacency_list: HashMap<usize, Vec<usize>>,
+    node_count: usize,
 }
 
 impl DirectedGraph {
-    fn new() -> Self {
+    pub fn new() -> Self {
         DirectedGraph {
             adjacency_list: HashMap::new(),
+            node_count: 0,
         }
     }
 
-    fn add_edge(&mut self, from: usize, to: usize) {
+    pub fn add_edge(&mut self, from: usize, to: usize) {
         self.adjacency_list.entry(from).or_insert(vec![]).push(to);
+        self.node_count = self.node_count.max(from.max(to) + 1);
     }
 }
 
-fn main() {
-    let mut graph = DirectedGraph::new();
-    graph.add_edge(0, 1);
-    graph.add_edge(0, 2);
-    graph.add_edge(1, 2);
-    graph.add_edge(2, 0);
-    graph.add_edge(2, 3);
+impl GraphView for DirectedGraph {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
 
-    println!("{:?}", graph);
-}
\ No newline at end of file
+    fn is_directed(&self) -> bool {
+        true
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, ())> {
+        self.adjacency_list.get(&node).into_iter().flatten().map(|&v| (v, ()))
+    }
+}
@@ -1,24 +1,38 @@
-#[derive(Debug)]
-struct EdgeListGraph {
+use super::generic_graph::VertexId;
+use super::graph_view::GraphView;
+
+/// Each `(u, v)` pair is one directed edge
+#[derive(Debug, Default)]
+pub struct EdgeListGraph {
     edges: Vec<(usize, usize)>,
+    node_count: usize,
 }
 
 impl EdgeListGraph {
-    fn new() -> Self {
-        EdgeListGraph { edges: vec![] }
+    pub fn new() -> Self {
+        EdgeListGraph { edges: vec![], node_count: 0 }
     }
 
-    fn add_edge(&mut self, u: usize, v: usize) {
+    pub fn add_edge(&mut self, u: usize, v: usize) {
         self.edges.push((u, v));
+        self.node_count = self.node_count.max(u.max(v) + 1);
     }
 }
 
-fn main() {
-    let mut graph = EdgeListGraph::new();
-    graph.add_edge(0, 1);
-    graph.add_edge(0, 2);
-    graph.add_edge(1, 2);
-    graph.add_edge(2, 3);
+impl GraphView for EdgeListGraph {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
 
-    println!("{:?}", graph);
-}
\ No newline at end of file
+    fn is_directed(&self) -> bool {
+        true
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, ())> {
+        self.edges.iter()
+            .filter(move |(u, _)| *u == node)
+            .map(|&(_, v)| (v, ()))
+    }
+}
@@ -1,6 +1,10 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+use super::csr_graph::CsrGraph;
+use super::graph_view::GraphView;
 
 /// Enum to represent edge direction
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,31 +17,101 @@ pub enum EdgeDirection {
 pub trait Weight: Clone + Debug + PartialOrd + Default {}
 impl<T: Clone + Debug + PartialOrd + Default> Weight for T {}
 
+/// Numeric edge weight supporting the path arithmetic of weighted algorithms
+pub trait NumericWeight: Weight + Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// Additive identity, the length of an empty path
+    fn zero() -> Self;
+
+    /// Larger than any path length, used for unreachable vertices
+    fn infinity() -> Self;
+
+    /// Addition that reports overflow as `None`
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer_weight {
+    ($($t:ty),*) => {$(
+        impl NumericWeight for $t {
+            fn zero() -> Self { 0 }
+            fn infinity() -> Self { <$t>::MAX }
+            fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+        }
+    )*};
+}
+impl_integer_weight!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float_weight {
+    ($($t:ty),*) => {$(
+        impl NumericWeight for $t {
+            fn zero() -> Self { 0.0 }
+            fn infinity() -> Self { <$t>::INFINITY }
+            fn checked_add(self, other: Self) -> Option<Self> { Some(self + other) }
+        }
+    )*};
+}
+impl_float_weight!(f32, f64);
+
+/// Dense index assigned to a vertex when it is first inserted
+pub type VertexId = usize;
+
 /// Generic Graph structure
+///
+/// Vertices are interned into dense `VertexId`s in insertion order, so the
+/// id of a vertex never changes while vertices and edges are being added.
 #[derive(Debug)]
 pub struct Graph<V, E = ()>
 where
     V: Eq + Hash + Clone + Debug,
     E: Weight,
 {
-    vertices: HashSet<V>,
-    edges: Vec<(V, V, E)>,
+    vertices: Vec<V>,
+    vertex_ids: HashMap<V, VertexId>,
+    edges: Vec<(VertexId, VertexId, E)>,
     direction: EdgeDirection,
-    representation: GraphRepresentation<V, E>,
+    representation: GraphRepresentation<E>,
+}
+
+/// Storage layout of a graph, chosen at construction or with `convert_to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepresentationKind {
+    AdjacencyList,
+    AdjacencyMatrix,
+    EdgeList,
 }
 
-/// Different graph representations
+/// Different graph representations, indexed by `VertexId`
 #[derive(Debug)]
-enum GraphRepresentation<V, E>
+enum GraphRepresentation<E>
 where
-    V: Eq + Hash + Clone + Debug,
     E: Weight,
 {
-    AdjacencyList(HashMap<V, Vec<(V, E)>>),
-    AdjacencyMatrix(Vec<Vec<Option<E>>>),
+    /// `lists[from]` holds `(to, weight)` for every outgoing edge
+    AdjacencyList(Vec<Vec<(VertexId, E)>>),
+    /// `matrix[from][to]` holds the weights of all `from -> to` edges in insertion order
+    AdjacencyMatrix(Vec<Vec<Vec<E>>>),
+    /// Edges are only kept in the graph's edge vector
     EdgeList,
 }
 
+impl<E: Weight> GraphRepresentation<E> {
+    /// Empty storage of the given kind, to be filled by `rebuild_representation`
+    fn empty(kind: RepresentationKind) -> Self {
+        match kind {
+            RepresentationKind::AdjacencyList => GraphRepresentation::AdjacencyList(Vec::new()),
+            RepresentationKind::AdjacencyMatrix => GraphRepresentation::AdjacencyMatrix(Vec::new()),
+            RepresentationKind::EdgeList => GraphRepresentation::EdgeList,
+        }
+    }
+
+    fn kind(&self) -> RepresentationKind {
+        match self {
+            GraphRepresentation::AdjacencyList(_) => RepresentationKind::AdjacencyList,
+            GraphRepresentation::AdjacencyMatrix(_) => RepresentationKind::AdjacencyMatrix,
+            GraphRepresentation::EdgeList => RepresentationKind::EdgeList,
+        }
+    }
+}
+
 impl<V, E> Graph<V, E>
 where
     V: Eq + Hash + Clone + Debug,
@@ -46,119 +120,355 @@ where
     /// Create a new graph with specified parameters
     pub fn new(
         direction: EdgeDirection,
-        representation: GraphRepresentation<V, E>,
+        representation: RepresentationKind,
     ) -> Self {
-        Graph {
-            vertices: HashSet::new(),
+        let mut graph = Graph {
+            vertices: Vec::new(),
+            vertex_ids: HashMap::new(),
             edges: Vec::new(),
             direction,
-            representation,
+            representation: GraphRepresentation::empty(representation),
+        };
+        graph.rebuild_representation();
+        graph
+    }
+
+    /// Get the kind of the active representation
+    pub fn representation(&self) -> RepresentationKind {
+        self.representation.kind()
+    }
+
+    /// Migrate the graph to another representation in place.
+    /// Vertex ids and the edge order are preserved.
+    pub fn convert_to(&mut self, representation: RepresentationKind) {
+        self.representation = GraphRepresentation::empty(representation);
+        self.rebuild_representation();
+    }
+
+    /// Rebuild the active representation from `edges`
+    fn rebuild_representation(&mut self) {
+        let size = self.vertices.len();
+
+        match &mut self.representation {
+            GraphRepresentation::AdjacencyList(lists) => {
+                *lists = vec![Vec::new(); size];
+                for (from, to, weight) in &self.edges {
+                    lists[*from].push((*to, weight.clone()));
+                }
+            }
+            GraphRepresentation::AdjacencyMatrix(matrix) => {
+                *matrix = vec![vec![Vec::new(); size]; size];
+                for (from, to, weight) in &self.edges {
+                    matrix[*from][*to].push(weight.clone());
+                }
+            }
+            GraphRepresentation::EdgeList => {
+                // Edge list is already maintained in self.edges
+            }
         }
     }
 
-    /// Add a vertex to the graph
-    pub fn add_vertex(&mut self, vertex: V) {
-        self.vertices.insert(vertex);
-        self.update_representation();
+    /// Add a vertex to the graph, returning its id.
+    /// Adding an existing vertex returns the id it already has.
+    pub fn add_vertex(&mut self, vertex: V) -> VertexId {
+        if let Some(&id) = self.vertex_ids.get(&vertex) {
+            return id;
+        }
+
+        let id = self.vertices.len();
+        self.vertices.push(vertex.clone());
+        self.vertex_ids.insert(vertex, id);
+
+        match &mut self.representation {
+            GraphRepresentation::AdjacencyList(lists) => {
+                lists.push(Vec::new());
+            }
+            GraphRepresentation::AdjacencyMatrix(matrix) => {
+                for row in matrix.iter_mut() {
+                    row.push(Vec::new());
+                }
+                matrix.push(vec![Vec::new(); id + 1]);
+            }
+            GraphRepresentation::EdgeList => {}
+        }
+
+        id
     }
 
     /// Add an edge between two vertices with optional weight
     pub fn add_edge(&mut self, from: V, to: V, weight: E) {
-        self.vertices.insert(from.clone());
-        self.vertices.insert(to.clone());
-        self.edges.push((from.clone(), to.clone(), weight.clone()));
+        let from = self.add_vertex(from);
+        let to = self.add_vertex(to);
 
+        self.insert_edge(from, to, weight.clone());
         if self.direction == EdgeDirection::Undirected {
-            self.edges.push((to, from, weight));
+            self.insert_edge(to, from, weight);
         }
+    }
 
-        self.update_representation();
+    /// Record a single directed edge in `edges` and the active representation
+    fn insert_edge(&mut self, from: VertexId, to: VertexId, weight: E) {
+        match &mut self.representation {
+            GraphRepresentation::AdjacencyList(lists) => {
+                lists[from].push((to, weight.clone()));
+            }
+            GraphRepresentation::AdjacencyMatrix(matrix) => {
+                matrix[from][to].push(weight.clone());
+            }
+            GraphRepresentation::EdgeList => {
+                // Edge list is already maintained in self.edges
+            }
+        }
+        self.edges.push((from, to, weight));
     }
 
-    /// Update the internal representation based on edges
-    fn update_representation(&mut self) {
+    /// Remove a vertex together with all of its incident edges.
+    /// The last vertex takes over the id of the removed one, other ids are unchanged.
+    pub fn remove_vertex(&mut self, vertex: &V) -> Option<V> {
+        let id = self.vertex_id(vertex)?;
+        let last = self.vertices.len() - 1;
+        let remap = |v: VertexId| if v == last { id } else { v };
+
+        self.edges.retain(|(from, to, _)| *from != id && *to != id);
+        for (from, to, _) in self.edges.iter_mut() {
+            *from = remap(*from);
+            *to = remap(*to);
+        }
+
         match &mut self.representation {
-            GraphRepresentation::AdjacencyList(map) => {
-                *map = HashMap::new();
-                for (from, to, weight) in &self.edges {
-                    map.entry(from.clone())
-                        .or_insert_with(Vec::new)
-                        .push((to.clone(), weight.clone()));
+            GraphRepresentation::AdjacencyList(lists) => {
+                lists.swap_remove(id);
+                for list in lists.iter_mut() {
+                    list.retain(|(to, _)| *to != id);
+                    for (to, _) in list.iter_mut() {
+                        *to = remap(*to);
+                    }
                 }
             }
             GraphRepresentation::AdjacencyMatrix(matrix) => {
-                let vertices: Vec<V> = self.vertices.iter().cloned().collect();
-                let size = vertices.len();
-                *matrix = vec![vec![None; size]; size];
+                matrix.swap_remove(id);
+                for row in matrix.iter_mut() {
+                    row.swap_remove(id);
+                }
+            }
+            GraphRepresentation::EdgeList => {}
+        }
 
-                for (from, to, weight) in &self.edges {
-                    let from_idx = vertices.iter().position(|v| v == from).unwrap();
-                    let to_idx = vertices.iter().position(|v| v == to).unwrap();
-                    matrix[from_idx][to_idx] = Some(weight.clone());
+        let removed = self.vertices.swap_remove(id);
+        self.vertex_ids.remove(&removed);
+        if id != last {
+            *self.vertex_ids.get_mut(&self.vertices[id]).unwrap() = id;
+        }
+
+        Some(removed)
+    }
+
+    /// Remove one edge between two vertices, returning its weight.
+    /// For undirected graphs the mirrored edge is removed as well.
+    pub fn remove_edge(&mut self, from: &V, to: &V) -> Option<E> {
+        let (from, to) = (self.vertex_id(from)?, self.vertex_id(to)?);
+        let weight = self.take_edge(from, to)?;
+
+        if self.direction == EdgeDirection::Undirected {
+            self.take_edge(to, from);
+        }
+
+        Some(weight)
+    }
+
+    /// Remove the first stored edge `from -> to` from `edges` and the active representation
+    fn take_edge(&mut self, from: VertexId, to: VertexId) -> Option<E> {
+        let pos = self.edges.iter().position(|(f, t, _)| *f == from && *t == to)?;
+        let (_, _, weight) = self.edges.remove(pos);
+
+        match &mut self.representation {
+            GraphRepresentation::AdjacencyList(lists) => {
+                if let Some(pos) = lists[from].iter().position(|(v, _)| *v == to) {
+                    lists[from].remove(pos);
                 }
             }
-            GraphRepresentation::EdgeList => {
-                // Edge list is already maintained in self.edges
+            GraphRepresentation::AdjacencyMatrix(matrix) => {
+                matrix[from][to].remove(0);
             }
+            GraphRepresentation::EdgeList => {}
         }
+
+        Some(weight)
+    }
+
+    /// Replace the weight of an edge between two vertices, returning the old weight.
+    /// For undirected graphs the mirrored edge is updated as well.
+    pub fn update_weight(&mut self, from: &V, to: &V, weight: E) -> Option<E> {
+        let (from, to) = (self.vertex_id(from)?, self.vertex_id(to)?);
+        let old = self.replace_weight(from, to, 0, weight.clone())?;
+
+        if self.direction == EdgeDirection::Undirected {
+            // A self-loop stores its mirror as the second `from -> from` entry
+            let nth = if from == to { 1 } else { 0 };
+            self.replace_weight(to, from, nth, weight);
+        }
+
+        Some(old)
+    }
+
+    /// Replace the weight of the `nth` stored edge `from -> to`
+    fn replace_weight(&mut self, from: VertexId, to: VertexId, nth: usize, weight: E) -> Option<E> {
+        let edge = self.edges.iter_mut()
+            .filter(|(f, t, _)| *f == from && *t == to)
+            .nth(nth)?;
+        let old = std::mem::replace(&mut edge.2, weight.clone());
+
+        match &mut self.representation {
+            GraphRepresentation::AdjacencyList(lists) => {
+                if let Some(entry) = lists[from].iter_mut().filter(|(v, _)| *v == to).nth(nth) {
+                    entry.1 = weight;
+                }
+            }
+            GraphRepresentation::AdjacencyMatrix(matrix) => {
+                if let Some(entry) = matrix[from][to].get_mut(nth) {
+                    *entry = weight;
+                }
+            }
+            GraphRepresentation::EdgeList => {}
+        }
+
+        Some(old)
+    }
+
+    /// Remove all vertices and edges, keeping the direction and representation kind
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.vertex_ids.clear();
+        self.edges.clear();
+
+        match &mut self.representation {
+            GraphRepresentation::AdjacencyList(lists) => lists.clear(),
+            GraphRepresentation::AdjacencyMatrix(matrix) => matrix.clear(),
+            GraphRepresentation::EdgeList => {}
+        }
+    }
+
+    /// Take a read-only compressed sparse row snapshot of the graph.
+    /// Vertex ids are the same in the snapshot.
+    pub fn freeze(&self) -> CsrGraph<V, E> {
+        CsrGraph::new(
+            self.vertices.clone(),
+            self.vertex_ids.clone(),
+            self.direction,
+            &self.edges,
+        )
+    }
+
+    /// Get the direction of the edges
+    pub fn direction(&self) -> EdgeDirection {
+        self.direction
+    }
+
+    /// Get the id of a vertex
+    pub fn vertex_id(&self, vertex: &V) -> Option<VertexId> {
+        self.vertex_ids.get(vertex).copied()
+    }
+
+    /// Get the vertex stored under an id
+    pub fn vertex(&self, id: VertexId) -> Option<&V> {
+        self.vertices.get(id)
     }
 
     /// Get neighbors of a vertex
     pub fn neighbors(&self, vertex: &V) -> Vec<(&V, &E)> {
+        self.neighbors_iter(vertex).collect()
+    }
+
+    /// Iterate over the neighbors of a vertex without allocating
+    pub fn neighbors_iter(&self, vertex: &V) -> impl Iterator<Item = (&V, &E)> {
+        let edges = match self.vertex_id(vertex) {
+            Some(id) => self.out_edges(id),
+            None => OutEdges::Empty,
+        };
+        edges.map(|(to, w)| (&self.vertices[to], w))
+    }
+
+    /// Iterate over the vertices with an edge into `vertex`
+    pub fn in_neighbors(&self, vertex: &V) -> impl Iterator<Item = (&V, &E)> {
+        let edges = match self.vertex_id(vertex) {
+            Some(id) => self.in_edges(id),
+            None => InEdges::Empty,
+        };
+        edges.map(|(from, w)| (&self.vertices[from], w))
+    }
+
+    /// Iterate over `(to, weight)` of the outgoing edges of a vertex id
+    pub fn out_edges(&self, from: VertexId) -> OutEdges<'_, E> {
         match &self.representation {
-            GraphRepresentation::AdjacencyList(map) => {
-                map.get(vertex)
-                    .map(|neighbors| neighbors.iter().map(|(v, w)| (v, w)).collect())
-                    .unwrap_or_else(Vec::new)
-            }
+            GraphRepresentation::AdjacencyList(lists) => OutEdges::List(lists[from].iter()),
             GraphRepresentation::AdjacencyMatrix(matrix) => {
-                let vertices: Vec<&V> = self.vertices.iter().collect();
-                if let Some(from_idx) = vertices.iter().position(|&v| v == vertex) {
-                    vertices.iter()
-                        .enumerate()
-                        .filter_map(|(to_idx, v)| {
-                            matrix[from_idx][to_idx].as_ref().map(|w| (*v, w))
-                        })
-                        .collect()
-                } else {
-                    Vec::new()
-                }
+                OutEdges::Matrix(matrix[from].iter().enumerate(), 0, [].iter())
             }
-            GraphRepresentation::EdgeList => {
-                self.edges.iter()
-                    .filter(|(from, _, _)| from == vertex)
-                    .map(|(_, to, weight)| (to, weight))
-                    .collect()
+            GraphRepresentation::EdgeList => OutEdges::EdgeList(from, self.edges.iter()),
+        }
+    }
+
+    /// Iterate over `(from, weight)` of the incoming edges of a vertex id
+    pub fn in_edges(&self, to: VertexId) -> InEdges<'_, E> {
+        match &self.representation {
+            GraphRepresentation::AdjacencyMatrix(matrix) => {
+                InEdges::Matrix(to, matrix.iter().enumerate(), 0, [].iter())
             }
+            // Adjacency lists only index outgoing edges, so scan the edge vector
+            _ => InEdges::EdgeList(to, self.edges.iter()),
+        }
+    }
+
+    /// Iterate over all vertices in id order
+    pub fn vertices(&self) -> impl Iterator<Item = &V> {
+        self.vertices.iter()
+    }
+
+    /// Iterate over all stored edges, including the mirrored edges of undirected graphs
+    pub fn edges(&self) -> impl Iterator<Item = (&V, &V, &E)> {
+        self.edges.iter()
+            .map(|(from, to, w)| (&self.vertices[*from], &self.vertices[*to], w))
+    }
+
+    /// Get the number of edges leaving a vertex
+    pub fn out_degree(&self, vertex: &V) -> usize {
+        self.vertex_id(vertex).map_or(0, |id| self.out_edges(id).count())
+    }
+
+    /// Get the number of edges entering a vertex
+    pub fn in_degree(&self, vertex: &V) -> usize {
+        self.vertex_id(vertex).map_or(0, |id| self.in_edges(id).count())
+    }
+
+    /// Get the degree of a vertex: in plus out degree for directed graphs,
+    /// the number of incident edge ends for undirected graphs
+    pub fn degree(&self, vertex: &V) -> usize {
+        match self.direction {
+            EdgeDirection::Directed => self.out_degree(vertex) + self.in_degree(vertex),
+            EdgeDirection::Undirected => self.out_degree(vertex),
         }
     }
 
     /// Check if the graph contains a vertex
     pub fn contains_vertex(&self, vertex: &V) -> bool {
-        self.vertices.contains(vertex)
+        self.vertex_ids.contains_key(vertex)
     }
 
     /// Check if an edge exists between two vertices
     pub fn has_edge(&self, from: &V, to: &V) -> bool {
+        let (Some(from), Some(to)) = (self.vertex_id(from), self.vertex_id(to)) else {
+            return false;
+        };
+
         match &self.representation {
-            GraphRepresentation::AdjacencyList(map) => {
-                map.get(from).map_or(false, |neighbors| {
-                    neighbors.iter().any(|(v, _)| v == to)
-                })
+            GraphRepresentation::AdjacencyList(lists) => {
+                lists[from].iter().any(|(v, _)| *v == to)
             }
             GraphRepresentation::AdjacencyMatrix(matrix) => {
-                let vertices: Vec<&V> = self.vertices.iter().collect();
-                if let (Some(from_idx), Some(to_idx)) = (
-                    vertices.iter().position(|&v| v == from),
-                    vertices.iter().position(|&v| v == to),
-                ) {
-                    matrix[from_idx][to_idx].is_some()
-                } else {
-                    false
-                }
+                !matrix[from][to].is_empty()
             }
             GraphRepresentation::EdgeList => {
-                self.edges.iter().any(|(f, t, _)| f == from && t == to)
+                self.edges.iter().any(|(f, t, _)| *f == from && *t == to)
             }
         }
     }
@@ -174,6 +484,89 @@ where
     }
 }
 
+impl<V, E> GraphView for Graph<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    type Weight = E;
+
+    fn node_count(&self) -> usize {
+        self.vertex_count()
+    }
+
+    fn is_directed(&self) -> bool {
+        self.direction == EdgeDirection::Directed
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, E)> {
+        self.out_edges(node).map(|(to, w)| (to, w.clone()))
+    }
+
+    fn edge_weight(&self, from: VertexId, to: VertexId) -> Option<E> {
+        match &self.representation {
+            GraphRepresentation::AdjacencyMatrix(matrix) => matrix[from][to].first().cloned(),
+            _ => self.out_edges(from).find(|(v, _)| *v == to).map(|(_, w)| w.clone()),
+        }
+    }
+}
+
+/// Iterator over the outgoing edges of a vertex, see `Graph::out_edges`
+pub enum OutEdges<'a, E> {
+    List(std::slice::Iter<'a, (VertexId, E)>),
+    /// Remaining cells of the row, and the target and edges left of the current cell
+    Matrix(std::iter::Enumerate<std::slice::Iter<'a, Vec<E>>>, VertexId, std::slice::Iter<'a, E>),
+    EdgeList(VertexId, std::slice::Iter<'a, (VertexId, VertexId, E)>),
+    Empty,
+}
+
+impl<'a, E> Iterator for OutEdges<'a, E> {
+    type Item = (VertexId, &'a E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            OutEdges::List(iter) => iter.next().map(|(to, w)| (*to, w)),
+            OutEdges::Matrix(cells, to, weights) => loop {
+                if let Some(w) = weights.next() {
+                    return Some((*to, w));
+                }
+                (*to, *weights) = cells.next().map(|(to, cell)| (to, cell.iter()))?;
+            },
+            OutEdges::EdgeList(from, iter) => {
+                iter.find(|(f, _, _)| f == from).map(|(_, to, w)| (*to, w))
+            }
+            OutEdges::Empty => None,
+        }
+    }
+}
+
+/// Iterator over the incoming edges of a vertex, see `Graph::in_edges`
+pub enum InEdges<'a, E> {
+    /// Target, remaining rows, and the source and edges left of the current cell
+    Matrix(VertexId, std::iter::Enumerate<std::slice::Iter<'a, Vec<Vec<E>>>>, VertexId, std::slice::Iter<'a, E>),
+    EdgeList(VertexId, std::slice::Iter<'a, (VertexId, VertexId, E)>),
+    Empty,
+}
+
+impl<'a, E> Iterator for InEdges<'a, E> {
+    type Item = (VertexId, &'a E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            InEdges::Matrix(to, rows, from, weights) => loop {
+                if let Some(w) = weights.next() {
+                    return Some((*from, w));
+                }
+                (*from, *weights) = rows.next().map(|(from, row)| (from, row[*to].iter()))?;
+            },
+            InEdges::EdgeList(to, iter) => {
+                iter.find(|(_, t, _)| t == to).map(|(from, _, w)| (*from, w))
+            }
+            InEdges::Empty => None,
+        }
+    }
+}
+
 /// Builder pattern for Graph
 pub struct GraphBuilder<V, E>
 where
@@ -181,7 +574,7 @@ where
     E: Weight,
 {
     direction: EdgeDirection,
-    representation: GraphRepresentation<V, E>,
+    representation: RepresentationKind,
     vertices: Vec<V>,
     edges: Vec<(V, V, E)>,
 }
@@ -194,7 +587,7 @@ where
     pub fn new() -> Self {
         Self {
             direction: EdgeDirection::Undirected,
-            representation: GraphRepresentation::AdjacencyList(HashMap::new()),
+            representation: RepresentationKind::AdjacencyList,
             vertices: Vec::new(),
             edges: Vec::new(),
         }
@@ -211,17 +604,17 @@ where
     }
 
     pub fn with_adjacency_list(mut self) -> Self {
-        self.representation = GraphRepresentation::AdjacencyList(HashMap::new());
+        self.representation = RepresentationKind::AdjacencyList;
         self
     }
 
     pub fn with_adjacency_matrix(mut self) -> Self {
-        self.representation = GraphRepresentation::AdjacencyMatrix(Vec::new());
+        self.representation = RepresentationKind::AdjacencyMatrix;
         self
     }
 
     pub fn with_edge_list(mut self) -> Self {
-        self.representation = GraphRepresentation::EdgeList;
+        self.representation = RepresentationKind::EdgeList;
         self
     }
 
@@ -248,4 +641,14 @@ where
 
         graph
     }
-}
\ No newline at end of file
+}
+
+impl<V, E> Default for GraphBuilder<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
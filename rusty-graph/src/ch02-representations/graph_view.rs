@@ -0,0 +1,25 @@
+use super::generic_graph::VertexId;
+
+/// Read-only interface shared by every graph representation in this chapter,
+/// so an algorithm can be written once and run on any of them.
+///
+/// Nodes are addressed by dense ids in `0..node_count()`. Undirected graphs
+/// report every edge from both of its endpoints.
+pub trait GraphView {
+    /// Edge weight, `()` for unweighted graphs
+    type Weight: Clone;
+
+    /// Get the number of node ids, i.e. one past the largest id in use
+    fn node_count(&self) -> usize;
+
+    /// Check whether edges are one-way
+    fn is_directed(&self) -> bool;
+
+    /// Iterate over `(neighbor, weight)` of the edges leaving a node
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, Self::Weight)>;
+
+    /// Get the weight of an edge between two nodes, if there is one
+    fn edge_weight(&self, from: VertexId, to: VertexId) -> Option<Self::Weight> {
+        self.adjacent(from).find(|(v, _)| *v == to).map(|(_, w)| w)
+    }
+}
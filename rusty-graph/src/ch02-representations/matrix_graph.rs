@@ -1,18 +1,21 @@
+use super::generic_graph::VertexId;
+use super::graph_view::GraphView;
+
 #[derive(Debug)]
-struct MatrixGraph {
+pub struct MatrixGraph {
     matrix: Vec<Vec<usize>>,
     size: usize,
 }
 
 impl MatrixGraph {
-    fn new(size: usize) -> Self {
+    pub fn new(size: usize) -> Self {
         MatrixGraph {
             matrix: vec![vec![0; size]; size],
             size,
         }
     }
 
-    fn add_edge(&mut self, u: usize, v: usize) {
+    pub fn add_edge(&mut self, u: usize, v: usize) {
         if u < self.size && v < self.size {
             self.matrix[u][v] = 1;
             // For undirected graph, add this line:
@@ -21,12 +24,25 @@ impl MatrixGraph {
     }
 }
 
-fn main() {
-    let mut graph = MatrixGraph::new(4);
-    graph.add_edge(0, 1);
-    graph.add_edge(0, 2);
-    graph.add_edge(1, 2);
-    graph.add_edge(2, 3);
+impl GraphView for MatrixGraph {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.size
+    }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
 
-    println!("{:?}", graph.matrix);
-}
\ No newline at end of file
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, ())> {
+        self.matrix[node].iter()
+            .enumerate()
+            .filter(|(_, cell)| **cell != 0)
+            .map(|(v, _)| (v, ()))
+    }
+
+    fn edge_weight(&self, from: VertexId, to: VertexId) -> Option<()> {
+        (self.matrix[from][to] != 0).then_some(())
+    }
+}
@@ -1,6 +1,14 @@
 pub mod undirected_graph;
-mod directed_graph;
-mod weighted_graph;
-mod matrix_graph;
-mod edge_list;
-mod generic_graph;
+pub mod directed_graph;
+pub mod weighted_graph;
+pub mod matrix_graph;
+pub mod edge_list;
+pub mod generic_graph;
+pub mod csr_graph;
+pub mod graph_view;
+
+pub use csr_graph::CsrGraph;
+pub use generic_graph::{
+    EdgeDirection, Graph, GraphBuilder, NumericWeight, RepresentationKind, VertexId, Weight,
+};
+pub use graph_view::GraphView;
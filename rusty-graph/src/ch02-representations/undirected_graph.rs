@@ -1,29 +1,41 @@
 use std::collections::HashMap;
 
-#[derive(Debug)]
-struct UndirectedGraph {
+use super::generic_graph::VertexId;
+use super::graph_view::GraphView;
+
+#[derive(Debug, Default)]
+pub struct UndirectedGraph {
     adjacency_list: HashMap<usize, Vec<usize>>,
+    node_count: usize,
 }
 
 impl UndirectedGraph {
-    fn new() -> Self {
+    pub fn new() -> Self {
         UndirectedGraph {
             adjacency_list: HashMap::new(),
+            node_count: 0,
         }
     }
 
-    fn add_edge(&mut self, u: usize, v: usize) {
+    pub fn add_edge(&mut self, u: usize, v: usize) {
         self.adjacency_list.entry(u).or_insert(vec![]).push(v);
         self.adjacency_list.entry(v).or_insert(vec![]).push(u);
+        self.node_count = self.node_count.max(u.max(v) + 1);
     }
 }
 
-fn main() {
-    let mut graph = UndirectedGraph::new();
-    graph.add_edge(0, 1);
-    graph.add_edge(0, 2);
-    graph.add_edge(1, 2);
-    graph.add_edge(2, 3);
+impl GraphView for UndirectedGraph {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
 
-    println!("{:?}", graph);
-}
\ No newline at end of file
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, ())> {
+        self.adjacency_list.get(&node).into_iter().flatten().map(|&v| (v, ()))
+    }
+}
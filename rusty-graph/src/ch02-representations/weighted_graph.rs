@@ -1,30 +1,42 @@
 use std::collections::HashMap;
 
-#[derive(Debug)]
-struct WeightedGraph {
+use super::generic_graph::VertexId;
+use super::graph_view::GraphView;
+
+#[derive(Debug, Default)]
+pub struct WeightedGraph {
     adjacency_list: HashMap<usize, Vec<(usize, i32)>>,
+    node_count: usize,
 }
 
 impl WeightedGraph {
-    fn new() -> Self {
+    pub fn new() -> Self {
         WeightedGraph {
             adjacency_list: HashMap::new(),
+            node_count: 0,
         }
     }
 
-    fn add_edge(&mut self, u: usize, v: usize, weight: i32) {
+    pub fn add_edge(&mut self, u: usize, v: usize, weight: i32) {
         self.adjacency_list.entry(u).or_insert(vec![]).push((v, weight));
         // For undirected weighted graph, add the reverse edge too
         self.adjacency_list.entry(v).or_insert(vec![]).push((u, weight));
+        self.node_count = self.node_count.max(u.max(v) + 1);
     }
 }
 
-fn main() {
-    let mut graph = WeightedGraph::new();
-    graph.add_edge(0, 1, 4);
-    graph.add_edge(0, 2, 1);
-    graph.add_edge(1, 2, 2);
-    graph.add_edge(2, 3, 5);
+impl GraphView for WeightedGraph {
+    type Weight = i32;
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
 
-    println!("{:?}", graph);
-}
\ No newline at end of file
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, i32)> {
+        self.adjacency_list.get(&node).into_iter().flatten().copied()
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::VecDeque;
+
+use crate::representations::{GraphView, VertexId};
+
+use super::visitor::{Control, EdgeKind, OrderRecorder, SearchResult, Visitor};
+
+/// Iterative breadth-first search from one or more sources
+///
+/// All sources start at level 0, as in multi-source BFS. A vertex is
+/// discovered when it is enqueued and finished once its edges have been
+/// examined. BFS only tells tree edges apart: every other edge is reported
+/// as `Cross`, and undirected graphs report each edge once.
+pub fn bfs<G, Vis>(
+    graph: &G,
+    sources: impl IntoIterator<Item = VertexId>,
+    visitor: &mut Vis,
+) -> SearchResult
+where
+    G: GraphView,
+    Vis: Visitor,
+{
+    let mut search = SearchResult::new(graph.node_count());
+    let undirected = !graph.is_directed();
+    let mut queue = VecDeque::new();
+
+    for source in sources {
+        if search.discovered[source].is_some() {
+            continue;
+        }
+        match search.enter(source, visitor) {
+            Control::Break => return search.interrupt(),
+            Control::Prune => {
+                if search.leave(source, visitor) == Control::Break {
+                    return search.interrupt();
+                }
+            }
+            Control::Continue => queue.push_back(source),
+        }
+    }
+
+    while let Some(u) = queue.pop_front() {
+        // Undirected graphs: the first edge back to the parent mirrors the tree edge
+        let mut skip_parent = undirected;
+
+        for (v, _) in graph.adjacent(u) {
+            if skip_parent && search.parent[u] == Some(v) {
+                skip_parent = false;
+                continue;
+            }
+
+            let kind = match (search.discovered[v], search.finished[v]) {
+                (None, _) => EdgeKind::Tree,
+                // Already reported from the other endpoint
+                (Some(_), Some(_)) if undirected => continue,
+                _ => EdgeKind::Cross,
+            };
+
+            match visitor.edge(u, v, kind) {
+                Control::Break => return search.interrupt(),
+                Control::Prune if kind == EdgeKind::Tree => continue,
+                _ => {}
+            }
+            if kind != EdgeKind::Tree {
+                continue;
+            }
+
+            search.parent[v] = Some(u);
+            match search.enter(v, visitor) {
+                Control::Break => return search.interrupt(),
+                Control::Prune => {
+                    if search.leave(v, visitor) == Control::Break {
+                        return search.interrupt();
+                    }
+                }
+                Control::Continue => queue.push_back(v),
+            }
+        }
+
+        if search.leave(u, visitor) == Control::Break {
+            return search.interrupt();
+        }
+    }
+
+    search
+}
+
+/// Vertices reachable from `start` in BFS order
+pub fn bfs_order<G: GraphView>(graph: &G, start: VertexId) -> Vec<VertexId> {
+    let mut recorder = OrderRecorder::default();
+    bfs(graph, [start], &mut recorder);
+    recorder.pre
+}
+
+/// Number of edges on a shortest path from `start` to every vertex,
+/// `None` for unreachable vertices
+pub fn bfs_distances<G: GraphView>(graph: &G, start: VertexId) -> Vec<Option<usize>> {
+    let mut recorder = OrderRecorder::default();
+    let search = bfs(graph, [start], &mut recorder);
+    let mut distance = vec![None; graph.node_count()];
+
+    // Discovery order is level order, so a parent always has its distance first
+    for v in recorder.pre {
+        distance[v] = Some(search.parent[v].map_or(0, |p| distance[p].unwrap() + 1));
+    }
+
+    distance
+}
@@ -0,0 +1,115 @@
+use crate::representations::{GraphView, VertexId};
+
+use super::visitor::{Control, EdgeKind, OrderRecorder, SearchResult, Visitor};
+
+/// A vertex on the explicit DFS stack with its remaining edges
+struct Frame<I> {
+    vertex: VertexId,
+    edges: I,
+    skip_parent: bool, // Undirected graphs: the first edge back to the parent mirrors the tree edge
+}
+
+/// Iterative depth-first search
+///
+/// Every root that is still undiscovered starts a new tree, so passing
+/// `0..graph.node_count()` searches the whole graph as a forest. For
+/// undirected graphs each edge is reported once, as `Tree` or `Back`.
+pub fn dfs<G, Vis>(
+    graph: &G,
+    roots: impl IntoIterator<Item = VertexId>,
+    visitor: &mut Vis,
+) -> SearchResult
+where
+    G: GraphView,
+    Vis: Visitor,
+{
+    let mut search = SearchResult::new(graph.node_count());
+    let undirected = !graph.is_directed();
+
+    for root in roots {
+        if search.discovered[root].is_some() {
+            continue;
+        }
+
+        let mut stack = Vec::new();
+        match search.enter(root, visitor) {
+            Control::Break => return search.interrupt(),
+            Control::Prune => {
+                if search.leave(root, visitor) == Control::Break {
+                    return search.interrupt();
+                }
+                continue;
+            }
+            Control::Continue => stack.push(Frame {
+                vertex: root,
+                edges: graph.adjacent(root),
+                skip_parent: undirected,
+            }),
+        }
+
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.vertex;
+            let Some((v, _)) = frame.edges.next() else {
+                stack.pop();
+                if search.leave(u, visitor) == Control::Break {
+                    return search.interrupt();
+                }
+                continue;
+            };
+
+            if frame.skip_parent && search.parent[u] == Some(v) {
+                frame.skip_parent = false;
+                continue;
+            }
+
+            let kind = match (search.discovered[v], search.finished[v]) {
+                (None, _) => EdgeKind::Tree,
+                (Some(_), None) => EdgeKind::Back,
+                // Already reported as a back edge from the other endpoint
+                (Some(_), Some(_)) if undirected => continue,
+                (Some(d), Some(_)) if d > search.discovered[u].unwrap() => EdgeKind::Forward,
+                (Some(_), Some(_)) => EdgeKind::Cross,
+            };
+
+            match visitor.edge(u, v, kind) {
+                Control::Break => return search.interrupt(),
+                Control::Prune if kind == EdgeKind::Tree => continue,
+                _ => {}
+            }
+            if kind != EdgeKind::Tree {
+                continue;
+            }
+
+            search.parent[v] = Some(u);
+            match search.enter(v, visitor) {
+                Control::Break => return search.interrupt(),
+                Control::Prune => {
+                    if search.leave(v, visitor) == Control::Break {
+                        return search.interrupt();
+                    }
+                }
+                Control::Continue => stack.push(Frame {
+                    vertex: v,
+                    edges: graph.adjacent(v),
+                    skip_parent: undirected,
+                }),
+            }
+        }
+    }
+
+    search
+}
+
+/// Vertices reachable from `start` in DFS pre-order
+pub fn dfs_preorder<G: GraphView>(graph: &G, start: VertexId) -> Vec<VertexId> {
+    let mut recorder = OrderRecorder::default();
+    dfs(graph, [start], &mut recorder);
+    recorder.pre
+}
+
+/// Vertices reachable from `start` in DFS post-order
+pub fn dfs_postorder<G: GraphView>(graph: &G, start: VertexId) -> Vec<VertexId> {
+    let mut recorder = OrderRecorder::default();
+    dfs(graph, [start], &mut recorder);
+    recorder.post
+}
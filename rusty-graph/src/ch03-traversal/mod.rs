@@ -0,0 +1,7 @@
+pub mod visitor;
+pub mod bfs;
+pub mod dfs;
+
+pub use bfs::{bfs, bfs_distances, bfs_order};
+pub use dfs::{dfs, dfs_postorder, dfs_preorder};
+pub use visitor::{Control, EdgeKind, SearchResult, Visitor};
@@ -0,0 +1,120 @@
+use crate::representations::VertexId;
+
+/// Classification of an examined edge relative to the search forest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// The edge discovers its target
+    Tree,
+    /// The target is an ancestor still on the search stack
+    Back,
+    /// The target is an already finished descendant
+    Forward,
+    /// Any other edge
+    Cross,
+}
+
+/// Tells the search how to proceed after an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Control {
+    #[default]
+    Continue,
+    /// From `discover`: skip the edges of this vertex.
+    /// From a tree `edge`: do not follow it.
+    Prune,
+    /// Stop the whole search
+    Break,
+}
+
+/// Callbacks invoked by `bfs` and `dfs`; every method defaults to `Continue`
+pub trait Visitor {
+    /// A vertex is reached for the first time (pre-order)
+    fn discover(&mut self, _vertex: VertexId, _time: usize) -> Control {
+        Control::Continue
+    }
+
+    /// All edges of a vertex have been examined (post-order)
+    fn finish(&mut self, _vertex: VertexId, _time: usize) -> Control {
+        Control::Continue
+    }
+
+    /// An edge is examined, before the target of a tree edge is discovered
+    fn edge(&mut self, _from: VertexId, _to: VertexId, _kind: EdgeKind) -> Control {
+        Control::Continue
+    }
+}
+
+/// Discovery/finish timestamps and search-forest parents of a traversal.
+/// Both timestamps come from one counter that ticks on every event.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub discovered: Vec<Option<usize>>,
+    pub finished: Vec<Option<usize>>,
+    pub parent: Vec<Option<VertexId>>,
+    /// Whether a visitor stopped the search with `Control::Break`
+    pub interrupted: bool,
+    time: usize,
+}
+
+impl SearchResult {
+    pub(crate) fn new(node_count: usize) -> Self {
+        Self {
+            discovered: vec![None; node_count],
+            finished: vec![None; node_count],
+            parent: vec![None; node_count],
+            interrupted: false,
+            time: 0,
+        }
+    }
+
+    /// Follow the parents from `target` back to its search root
+    pub fn path_to(&self, target: VertexId) -> Option<Vec<VertexId>> {
+        self.discovered[target]?;
+
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(parent) = self.parent[current] {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub(crate) fn enter<Vis: Visitor>(&mut self, vertex: VertexId, visitor: &mut Vis) -> Control {
+        self.discovered[vertex] = Some(self.time);
+        let control = visitor.discover(vertex, self.time);
+        self.time += 1;
+        control
+    }
+
+    pub(crate) fn leave<Vis: Visitor>(&mut self, vertex: VertexId, visitor: &mut Vis) -> Control {
+        self.finished[vertex] = Some(self.time);
+        let control = visitor.finish(vertex, self.time);
+        self.time += 1;
+        control
+    }
+
+    pub(crate) fn interrupt(mut self) -> Self {
+        self.interrupted = true;
+        self
+    }
+}
+
+/// Records pre-order and post-order vertex sequences
+#[derive(Debug, Default)]
+pub(crate) struct OrderRecorder {
+    pub pre: Vec<VertexId>,
+    pub post: Vec<VertexId>,
+}
+
+impl Visitor for OrderRecorder {
+    fn discover(&mut self, vertex: VertexId, _time: usize) -> Control {
+        self.pre.push(vertex);
+        Control::Continue
+    }
+
+    fn finish(&mut self, vertex: VertexId, _time: usize) -> Control {
+        self.post.push(vertex);
+        Control::Continue
+    }
+}
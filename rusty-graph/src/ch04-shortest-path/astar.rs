@@ -0,0 +1,80 @@
+use std::collections::BinaryHeap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+use super::dijkstra::MinScored;
+
+/// A* search for a shortest path between two vertices, O((V + E) log V) at worst.
+///
+/// `heuristic` estimates the remaining distance from a vertex to `target`.
+/// The result is optimal as long as it never overestimates; a heuristic that
+/// is merely admissible but not consistent may make vertices be expanded again.
+/// Returns the path length and the path, or `None` if `target` is unreachable.
+pub fn astar<V, E, H>(
+    graph: &Graph<V, E>,
+    source: &V,
+    target: &V,
+    mut heuristic: H,
+) -> Option<(E, Vec<V>)>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+    H: FnMut(&V) -> E,
+{
+    let (source, target) = (graph.vertex_id(source)?, graph.vertex_id(target)?);
+    let (cost, path) = astar_ids(graph, source, target, |v| heuristic(graph.vertex(v).unwrap()))?;
+    Some((cost, path.into_iter().map(|v| graph.vertex(v).unwrap().clone()).collect()))
+}
+
+/// Id-level A* over any `GraphView`
+pub(crate) fn astar_ids<G, H>(
+    graph: &G,
+    source: VertexId,
+    target: VertexId,
+    mut heuristic: H,
+) -> Option<(G::Weight, Vec<VertexId>)>
+where
+    G: GraphView,
+    G::Weight: NumericWeight,
+    H: FnMut(VertexId) -> G::Weight,
+{
+    let n = graph.node_count();
+    let mut costs: Vec<Option<G::Weight>> = vec![None; n];
+    let mut predecessors = vec![None; n];
+    let mut heap = BinaryHeap::new();
+
+    let zero = G::Weight::zero();
+    costs[source] = Some(zero);
+    heap.push(MinScored(heuristic(source), (source, zero)));
+
+    while let Some(MinScored(_, (u, cost))) = heap.pop() {
+        if u == target {
+            let mut path = vec![target];
+            while let Some(previous) = predecessors[*path.last().unwrap()] {
+                path.push(previous);
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        // Stale entry: u was reached more cheaply after this one was pushed
+        if costs[u].is_some_and(|best| best < cost) {
+            continue;
+        }
+
+        for (v, w) in graph.adjacent(u) {
+            let Some(candidate) = cost.checked_add(w) else {
+                continue;
+            };
+            if costs[v].is_none_or(|current| candidate < current) {
+                costs[v] = Some(candidate);
+                predecessors[v] = Some(u);
+                let estimate = candidate.checked_add(heuristic(v)).unwrap_or(G::Weight::infinity());
+                heap.push(MinScored(estimate, (v, candidate)));
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,88 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+use super::paths::{IdPaths, NegativeCycle, ShortestPaths};
+
+/// Bellman-Ford algorithm, O(V * E), allowing negative weights.
+/// Fails with a concrete cycle if a negative cycle is reachable from `source`.
+pub fn bellman_ford<V, E>(
+    graph: &Graph<V, E>,
+    source: &V,
+) -> Result<ShortestPaths<V, E>, NegativeCycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (distances, predecessors) = match graph.vertex_id(source) {
+        Some(s) => bellman_ford_ids(graph, s).map_err(|cycle| NegativeCycle {
+            cycle: cycle.into_iter().map(|v| graph.vertex(v).unwrap().clone()).collect(),
+        })?,
+        None => (vec![None; graph.vertex_count()], vec![None; graph.vertex_count()]),
+    };
+    Ok(ShortestPaths::from_ids(graph, source.clone(), distances, predecessors))
+}
+
+/// Id-level Bellman-Ford over any `GraphView`.
+/// The error holds the vertex ids of a negative cycle in edge order.
+pub(crate) fn bellman_ford_ids<G>(
+    graph: &G,
+    source: VertexId,
+) -> Result<IdPaths<G::Weight>, Vec<VertexId>>
+where
+    G: GraphView,
+    G::Weight: NumericWeight,
+{
+    let n = graph.node_count();
+    let mut distances = vec![None; n];
+    let mut predecessors = vec![None; n];
+    distances[source] = Some(G::Weight::zero());
+
+    // n - 1 rounds settle all shortest paths; a change in round n means a negative cycle
+    for round in 0..n {
+        let mut relaxed = None;
+
+        for u in 0..n {
+            let Some(du) = distances[u] else {
+                continue;
+            };
+            for (v, w) in graph.adjacent(u) {
+                let Some(candidate) = du.checked_add(w) else {
+                    continue;
+                };
+                if distances[v].is_none_or(|current| candidate < current) {
+                    distances[v] = Some(candidate);
+                    predecessors[v] = Some(u);
+                    relaxed = Some(v);
+                }
+            }
+        }
+
+        match relaxed {
+            None => break,
+            Some(v) if round == n - 1 => return Err(extract_cycle(&predecessors, v)),
+            Some(_) => {}
+        }
+    }
+
+    Ok((distances, predecessors))
+}
+
+/// Walk predecessors from a vertex relaxed in round n until the walk closes a cycle
+fn extract_cycle(predecessors: &[Option<VertexId>], start: VertexId) -> Vec<VertexId> {
+    // n steps back from a vertex relaxed in round n always land on the cycle
+    let mut on_cycle = start;
+    for _ in 0..predecessors.len() {
+        on_cycle = predecessors[on_cycle].unwrap();
+    }
+
+    let mut cycle = vec![on_cycle];
+    let mut current = predecessors[on_cycle].unwrap();
+    while current != on_cycle {
+        cycle.push(current);
+        current = predecessors[current].unwrap();
+    }
+    cycle.reverse();
+    cycle
+}
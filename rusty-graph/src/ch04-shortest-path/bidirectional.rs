@@ -0,0 +1,162 @@
+use std::collections::BinaryHeap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+use super::dijkstra::MinScored;
+
+/// Bidirectional Dijkstra for a shortest path between two vertices.
+///
+/// Searches forward from `source` and backward from `target` at the same time,
+/// stopping once the two frontiers prove no shorter meeting point can exist.
+/// Requires non-negative weights. For directed graphs the incoming edges are
+/// collected once up front so the backward search can follow them cheaply.
+/// Returns the path length and the path, or `None` if `target` is unreachable.
+pub fn bidirectional_dijkstra<V, E>(
+    graph: &Graph<V, E>,
+    source: &V,
+    target: &V,
+) -> Option<(E, Vec<V>)>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (source, target) = (graph.vertex_id(source)?, graph.vertex_id(target)?);
+    let n = graph.vertex_count();
+
+    let (cost, path) = if graph.is_directed() {
+        let mut incoming = vec![Vec::new(); n];
+        for u in 0..n {
+            for (v, w) in graph.adjacent(u) {
+                incoming[v].push((u, w));
+            }
+        }
+        bidirectional_ids(n, source, target, |u| graph.adjacent(u), |u| incoming[u].iter().copied())?
+    } else {
+        bidirectional_ids(n, source, target, |u| graph.adjacent(u), |u| graph.adjacent(u))?
+    };
+
+    Some((cost, path.into_iter().map(|v| graph.vertex(v).unwrap().clone()).collect()))
+}
+
+/// One direction of the search
+struct Frontier<W> {
+    distances: Vec<Option<W>>,
+    predecessors: Vec<Option<VertexId>>,
+    settled: Vec<bool>,
+    heap: BinaryHeap<MinScored<W, VertexId>>,
+}
+
+impl<W: NumericWeight> Frontier<W> {
+    fn new(n: usize, start: VertexId) -> Self {
+        let mut distances = vec![None; n];
+        distances[start] = Some(W::zero());
+        Self {
+            distances,
+            predecessors: vec![None; n],
+            settled: vec![false; n],
+            heap: BinaryHeap::from([MinScored(W::zero(), start)]),
+        }
+    }
+
+    /// Smallest tentative distance still queued
+    fn peek(&self) -> Option<W> {
+        self.heap.peek().map(|MinScored(d, _)| *d)
+    }
+
+    /// Pop the closest unsettled vertex
+    fn settle(&mut self) -> Option<(W, VertexId)> {
+        while let Some(MinScored(d, u)) = self.heap.pop() {
+            if !self.settled[u] {
+                self.settled[u] = true;
+                return Some((d, u));
+            }
+        }
+        None
+    }
+
+    /// Relax the edges of `u`, recording in `best` any shorter path through
+    /// a vertex the other frontier has already reached
+    fn relax(
+        &mut self,
+        u: VertexId,
+        d: W,
+        edges: impl Iterator<Item = (VertexId, W)>,
+        other: &Frontier<W>,
+        best: &mut Option<(W, VertexId)>,
+    ) {
+        for (v, w) in edges {
+            let Some(candidate) = d.checked_add(w) else {
+                continue;
+            };
+            if self.distances[v].is_some_and(|current| current <= candidate) {
+                continue;
+            }
+            self.distances[v] = Some(candidate);
+            self.predecessors[v] = Some(u);
+            self.heap.push(MinScored(candidate, v));
+
+            let through = other.distances[v].and_then(|rest| candidate.checked_add(rest));
+            if let Some(total) = through
+                && best.is_none_or(|(shortest, _)| total < shortest)
+            {
+                *best = Some((total, v));
+            }
+        }
+    }
+}
+
+/// Id-level bidirectional Dijkstra, given the outgoing and incoming edges of each vertex
+fn bidirectional_ids<W, FI, BI>(
+    n: usize,
+    source: VertexId,
+    target: VertexId,
+    forward: impl Fn(VertexId) -> FI,
+    backward: impl Fn(VertexId) -> BI,
+) -> Option<(W, Vec<VertexId>)>
+where
+    W: NumericWeight,
+    FI: Iterator<Item = (VertexId, W)>,
+    BI: Iterator<Item = (VertexId, W)>,
+{
+    if source == target {
+        return Some((W::zero(), vec![source]));
+    }
+
+    let mut ahead = Frontier::<W>::new(n, source);
+    let mut behind = Frontier::new(n, target);
+    let mut best = None;
+
+    // Once either side runs dry, everything it can reach has been relaxed
+    while let (Some(f), Some(b)) = (ahead.peek(), behind.peek()) {
+        // Every path still to be found is at least as long as the two frontiers together
+        if let Some((shortest, _)) = best
+            && f.checked_add(b).is_none_or(|bound| bound >= shortest)
+        {
+            break;
+        }
+
+        if f <= b {
+            if let Some((d, u)) = ahead.settle() {
+                ahead.relax(u, d, forward(u), &behind, &mut best);
+            }
+        } else if let Some((d, u)) = behind.settle() {
+            behind.relax(u, d, backward(u), &ahead, &mut best);
+        }
+    }
+
+    let (total, meeting) = best?;
+    let mut path = vec![meeting];
+    while let Some(previous) = ahead.predecessors[*path.last().unwrap()] {
+        path.push(previous);
+    }
+    path.reverse();
+    let mut current = meeting;
+    while let Some(next) = behind.predecessors[current] {
+        path.push(next);
+        current = next;
+    }
+
+    Some((total, path))
+}
@@ -0,0 +1,86 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+use super::paths::{IdPaths, ShortestPaths};
+
+/// Heap entry ordered by ascending score, turning `BinaryHeap` into a min-heap.
+/// Scores only need `PartialOrd`; incomparable scores (NaN) count as equal.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MinScored<K, T>(pub K, pub T);
+
+impl<K: PartialOrd, T> PartialEq for MinScored<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: PartialOrd, T> Eq for MinScored<K, T> {}
+
+impl<K: PartialOrd, T> PartialOrd for MinScored<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: PartialOrd, T> Ord for MinScored<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Dijkstra's algorithm with a binary heap, O((V + E) log V).
+/// Requires non-negative weights; returns empty maps if `source` is not in the graph.
+pub fn dijkstra<V, E>(graph: &Graph<V, E>, source: &V) -> ShortestPaths<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (distances, predecessors) = match graph.vertex_id(source) {
+        Some(s) => dijkstra_ids(graph, s),
+        None => (vec![None; graph.vertex_count()], vec![None; graph.vertex_count()]),
+    };
+    ShortestPaths::from_ids(graph, source.clone(), distances, predecessors)
+}
+
+/// Id-level Dijkstra over any `GraphView`, returning distances and predecessors
+pub(crate) fn dijkstra_ids<G>(
+    graph: &G,
+    source: VertexId,
+) -> IdPaths<G::Weight>
+where
+    G: GraphView,
+    G::Weight: NumericWeight,
+{
+    let n = graph.node_count();
+    let mut distances = vec![None; n];
+    let mut predecessors = vec![None; n];
+    let mut settled = vec![false; n];
+    let mut heap = BinaryHeap::new();
+
+    distances[source] = Some(G::Weight::zero());
+    heap.push(MinScored(G::Weight::zero(), source));
+
+    while let Some(MinScored(d, u)) = heap.pop() {
+        if settled[u] {
+            continue;
+        }
+        settled[u] = true;
+
+        for (v, w) in graph.adjacent(u) {
+            let Some(candidate) = d.checked_add(w) else {
+                continue;
+            };
+            if distances[v].is_none_or(|current| candidate < current) {
+                distances[v] = Some(candidate);
+                predecessors[v] = Some(u);
+                heap.push(MinScored(candidate, v));
+            }
+        }
+    }
+
+    (distances, predecessors)
+}
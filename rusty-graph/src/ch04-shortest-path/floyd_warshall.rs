@@ -0,0 +1,67 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight};
+
+use super::bellman_ford::bellman_ford_ids;
+use super::paths::{AllPairsShortestPaths, NegativeCycle};
+
+/// Floyd-Warshall all-pairs shortest paths, O(V^3), allowing negative weights.
+/// Fails with a concrete cycle if the graph has a negative cycle.
+pub fn floyd_warshall<V, E>(
+    graph: &Graph<V, E>,
+) -> Result<AllPairsShortestPaths<V, E>, NegativeCycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let n = graph.vertex_count();
+    let mut distances = vec![vec![None; n]; n];
+    let mut next = vec![vec![None; n]; n];
+
+    for u in 0..n {
+        distances[u][u] = Some(E::zero());
+        next[u][u] = Some(u);
+    }
+    for u in 0..n {
+        for (v, w) in graph.adjacent(u) {
+            if distances[u][v].is_none_or(|current| w < current) {
+                distances[u][v] = Some(w);
+                next[u][v] = Some(v);
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(ik) = distances[i][k] else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(candidate) = distances[k][j].and_then(|kj| ik.checked_add(kj)) else {
+                    continue;
+                };
+                if distances[i][j].is_none_or(|current| candidate < current) {
+                    distances[i][j] = Some(candidate);
+                    next[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+
+    // A vertex with a negative distance to itself can reach a negative cycle;
+    // Bellman-Ford from it recovers a concrete one
+    if let Some(v) = (0..n).find(|&v| distances[v][v].is_some_and(|d| d < E::zero())) {
+        let cycle = bellman_ford_ids(graph, v).unwrap_err();
+        return Err(NegativeCycle {
+            cycle: cycle.into_iter().map(|v| graph.vertex(v).unwrap().clone()).collect(),
+        });
+    }
+
+    Ok(AllPairsShortestPaths {
+        vertices: graph.vertices().cloned().collect(),
+        vertex_ids: graph.vertices().cloned().enumerate().map(|(id, v)| (v, id)).collect(),
+        distances,
+        next,
+    })
+}
@@ -0,0 +1,118 @@
+use crate::representations::{GraphView, VertexId};
+
+use super::astar::astar_ids;
+
+/// A cell position as `(row, col)`
+pub type Cell = (usize, usize);
+
+/// Orthogonal moves: up, down, left, right
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// The up to four orthogonal neighbours of a cell inside a `rows x cols` grid
+pub fn grid_neighbors(rows: usize, cols: usize, (row, col): Cell) -> impl Iterator<Item = Cell> {
+    DIRECTIONS.iter().filter_map(move |&(dr, dc)| {
+        let r = row.checked_add_signed(dr)?;
+        let c = col.checked_add_signed(dc)?;
+        (r < rows && c < cols).then_some((r, c))
+    })
+}
+
+/// Manhattan distance between two cells, the natural A* heuristic on a 4-connected grid
+pub fn manhattan((r1, c1): Cell, (r2, c2): Cell) -> usize {
+    r1.abs_diff(r2) + c1.abs_diff(c2)
+}
+
+/// A rectangular grid viewed as an undirected graph with unit-length edges
+/// between orthogonally adjacent passable cells.
+///
+/// Cell `(row, col)` has vertex id `row * cols + col`. Impassable cells keep
+/// their id but have no edges. The width is taken from the first row; cells
+/// missing from shorter rows count as walls.
+pub struct GridGraph<'a, T, P> {
+    cells: &'a [Vec<T>],
+    rows: usize,
+    cols: usize,
+    passable: P,
+}
+
+impl<'a, T, P> GridGraph<'a, T, P>
+where
+    P: Fn(&T) -> bool,
+{
+    /// Wrap a row-major grid, using `passable` to tell open cells from walls
+    pub fn new(cells: &'a [Vec<T>], passable: P) -> Self {
+        Self {
+            cells,
+            rows: cells.len(),
+            cols: cells.first().map_or(0, Vec::len),
+            passable,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Get the vertex id of a cell
+    pub fn id(&self, (row, col): Cell) -> VertexId {
+        row * self.cols + col
+    }
+
+    /// Get the cell of a vertex id
+    pub fn cell(&self, id: VertexId) -> Cell {
+        (id / self.cols, id % self.cols)
+    }
+
+    /// Check whether a cell is inside the grid and can be entered
+    pub fn is_passable(&self, (row, col): Cell) -> bool {
+        row < self.rows && col < self.cols && self.cells[row].get(col).is_some_and(&self.passable)
+    }
+
+    /// Iterate over the passable neighbours of a passable cell
+    pub fn neighbors(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        let open = self.is_passable(cell);
+        grid_neighbors(self.rows, self.cols, cell)
+            .filter(move |&next| open && self.is_passable(next))
+    }
+
+    /// A* between two cells with a custom heuristic.
+    /// Returns the number of steps and the cells along the way.
+    pub fn astar<H>(&self, start: Cell, goal: Cell, mut heuristic: H) -> Option<(usize, Vec<Cell>)>
+    where
+        H: FnMut(Cell) -> usize,
+    {
+        if !self.is_passable(start) || !self.is_passable(goal) {
+            return None;
+        }
+        let (steps, path) = astar_ids(self, self.id(start), self.id(goal), |v| heuristic(self.cell(v)))?;
+        Some((steps, path.into_iter().map(|v| self.cell(v)).collect()))
+    }
+
+    /// Shortest path between two cells using A* with the Manhattan heuristic
+    pub fn shortest_path(&self, start: Cell, goal: Cell) -> Option<(usize, Vec<Cell>)> {
+        self.astar(start, goal, |cell| manhattan(cell, goal))
+    }
+}
+
+impl<T, P> GraphView for GridGraph<'_, T, P>
+where
+    P: Fn(&T) -> bool,
+{
+    type Weight = usize;
+
+    fn node_count(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn adjacent(&self, node: VertexId) -> impl Iterator<Item = (VertexId, usize)> {
+        self.neighbors(self.cell(node)).map(|cell| (self.id(cell), 1))
+    }
+}
@@ -0,0 +1,15 @@
+pub mod paths;
+pub mod dijkstra;
+pub mod bellman_ford;
+pub mod floyd_warshall;
+pub mod astar;
+pub mod bidirectional;
+pub mod grid;
+
+pub use astar::astar;
+pub use bellman_ford::bellman_ford;
+pub use bidirectional::bidirectional_dijkstra;
+pub use dijkstra::dijkstra;
+pub use floyd_warshall::floyd_warshall;
+pub use grid::{Cell, GridGraph, grid_neighbors, manhattan};
+pub use paths::{AllPairsShortestPaths, NegativeCycle, ShortestPaths};
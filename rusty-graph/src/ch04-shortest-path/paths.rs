@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight, VertexId};
+
+/// Id-indexed distances and predecessors produced by the single-source algorithms
+pub(crate) type IdPaths<E> = (Vec<Option<E>>, Vec<Option<VertexId>>);
+
+/// Single-source shortest path distances with their predecessor tree
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<V, E> {
+    pub source: V,
+    /// Distance of every reachable vertex from the source
+    pub distances: HashMap<V, E>,
+    /// Previous vertex on a shortest path to every reachable vertex except the source
+    pub predecessors: HashMap<V, V>,
+}
+
+impl<V, E> ShortestPaths<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    /// Convert id-indexed results into vertex-keyed maps
+    pub(crate) fn from_ids(
+        graph: &Graph<V, E>,
+        source: V,
+        distances: Vec<Option<E>>,
+        predecessors: Vec<Option<VertexId>>,
+    ) -> Self {
+        let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+
+        Self {
+            source,
+            distances: distances.into_iter()
+                .enumerate()
+                .filter_map(|(v, d)| d.map(|d| (vertex(v), d)))
+                .collect(),
+            predecessors: predecessors.into_iter()
+                .enumerate()
+                .filter_map(|(v, p)| p.map(|p| (vertex(v), vertex(p))))
+                .collect(),
+        }
+    }
+
+    /// Get the shortest distance to a vertex, `None` if it is unreachable
+    pub fn distance(&self, target: &V) -> Option<E> {
+        self.distances.get(target).copied()
+    }
+
+    /// Reconstruct a shortest path from the source to `target`
+    pub fn path_to(&self, target: &V) -> Option<Vec<V>> {
+        self.distances.get(target)?;
+
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(previous) = self.predecessors.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// A cycle of negative total weight, which makes shortest paths undefined.
+/// Each vertex has an edge to the next one and the last vertex leads back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegativeCycle<V> {
+    pub cycle: Vec<V>,
+}
+
+impl<V: Debug> fmt::Display for NegativeCycle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle:")?;
+        for v in self.cycle.iter().chain(self.cycle.first()) {
+            write!(f, " {:?}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl<V: Debug> Error for NegativeCycle<V> {}
+
+/// Distances and next hops between every pair of vertices
+#[derive(Debug, Clone)]
+pub struct AllPairsShortestPaths<V, E> {
+    pub(crate) vertices: Vec<V>,
+    pub(crate) vertex_ids: HashMap<V, VertexId>,
+    pub(crate) distances: Vec<Vec<Option<E>>>,
+    pub(crate) next: Vec<Vec<Option<VertexId>>>,
+}
+
+impl<V, E> AllPairsShortestPaths<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    /// Get the shortest distance between two vertices, `None` if unreachable
+    pub fn distance(&self, from: &V, to: &V) -> Option<E> {
+        let (from, to) = (self.vertex_ids.get(from)?, self.vertex_ids.get(to)?);
+        self.distances[*from][*to]
+    }
+
+    /// Reconstruct a shortest path by following next hops
+    pub fn path(&self, from: &V, to: &V) -> Option<Vec<V>> {
+        let (mut current, to) = (*self.vertex_ids.get(from)?, *self.vertex_ids.get(to)?);
+        self.distances[current][to]?;
+
+        let mut path = vec![self.vertices[current].clone()];
+        while current != to {
+            current = self.next[current][to]?;
+            path.push(self.vertices[current].clone());
+        }
+        Some(path)
+    }
+}
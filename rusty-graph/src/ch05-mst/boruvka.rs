@@ -0,0 +1,62 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::connectivity::DisjointSet;
+use crate::representations::{Graph, NumericWeight};
+
+use super::spanning_tree::{SpanningTree, undirected_edges};
+
+/// Borůvka's algorithm, O(E log V).
+///
+/// Every round picks the cheapest edge leaving each component and adds them
+/// all at once, at least halving the number of components. Ties are broken
+/// by edge index so that the picked edges never close a cycle.
+pub fn boruvka<V, E>(graph: &Graph<V, E>) -> SpanningTree<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let n = graph.vertex_count();
+    let edges = undirected_edges(graph);
+    let mut forest = DisjointSet::new(n);
+    let mut chosen = Vec::new();
+
+    let lighter = |a: usize, b: usize| match edges[a].2.partial_cmp(&edges[b].2) {
+        Some(Ordering::Less) => true,
+        Some(Ordering::Greater) => false,
+        _ => a < b,
+    };
+
+    loop {
+        // Cheapest outgoing edge index per component root
+        let mut cheapest: Vec<Option<usize>> = vec![None; n];
+
+        for (index, &(u, v, _)) in edges.iter().enumerate() {
+            let (ru, rv) = (forest.find(u), forest.find(v));
+            if ru == rv {
+                continue;
+            }
+            for root in [ru, rv] {
+                if cheapest[root].is_none_or(|best| lighter(index, best)) {
+                    cheapest[root] = Some(index);
+                }
+            }
+        }
+
+        let mut merged = false;
+        for index in cheapest.into_iter().flatten() {
+            let (u, v, w) = edges[index];
+            // Two components may have picked the same edge
+            if forest.union(u, v) {
+                chosen.push((u, v, w));
+                merged = true;
+            }
+        }
+        if !merged {
+            break;
+        }
+    }
+
+    SpanningTree::from_ids(graph, chosen)
+}
@@ -0,0 +1,33 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::connectivity::DisjointSet;
+use crate::representations::{Graph, NumericWeight};
+
+use super::spanning_tree::{SpanningTree, undirected_edges};
+
+/// Kruskal's algorithm, O(E log E).
+/// Scans edges by increasing weight and keeps those joining two different trees.
+pub fn kruskal<V, E>(graph: &Graph<V, E>) -> SpanningTree<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let mut edges = undirected_edges(graph);
+    edges.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal));
+
+    let mut forest = DisjointSet::new(graph.vertex_count());
+    let mut chosen = Vec::new();
+
+    for (u, v, w) in edges {
+        if forest.union(u, v) {
+            chosen.push((u, v, w));
+            if forest.count() == 1 {
+                break;
+            }
+        }
+    }
+
+    SpanningTree::from_ids(graph, chosen)
+}
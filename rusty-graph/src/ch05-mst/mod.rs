@@ -0,0 +1,9 @@
+pub mod spanning_tree;
+pub mod kruskal;
+pub mod prim;
+pub mod boruvka;
+
+pub use boruvka::boruvka;
+pub use kruskal::kruskal;
+pub use prim::prim;
+pub use spanning_tree::SpanningTree;
@@ -0,0 +1,48 @@
+use std::collections::BinaryHeap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight};
+use crate::shortest_path::dijkstra::MinScored;
+
+use super::spanning_tree::{SpanningTree, undirected_edges};
+
+/// Prim's algorithm with a binary heap, O(E log V).
+/// Grows one tree at a time from the lowest unvisited vertex id.
+pub fn prim<V, E>(graph: &Graph<V, E>) -> SpanningTree<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let n = graph.vertex_count();
+    let mut adjacency = vec![Vec::new(); n];
+    for (u, v, w) in undirected_edges(graph) {
+        adjacency[u].push((v, w));
+        adjacency[v].push((u, w));
+    }
+
+    let mut in_tree = vec![false; n];
+    let mut chosen = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for root in 0..n {
+        if in_tree[root] {
+            continue;
+        }
+        in_tree[root] = true;
+        heap.extend(adjacency[root].iter().map(|&(v, w)| MinScored(w, (root, v))));
+
+        while let Some(MinScored(w, (u, v))) = heap.pop() {
+            if in_tree[v] {
+                continue;
+            }
+            in_tree[v] = true;
+            chosen.push((u, v, w));
+            heap.extend(adjacency[v].iter()
+                .filter(|&&(next, _)| !in_tree[next])
+                .map(|&(next, w)| MinScored(w, (v, next))));
+        }
+    }
+
+    SpanningTree::from_ids(graph, chosen)
+}
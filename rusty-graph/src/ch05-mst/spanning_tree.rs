@@ -0,0 +1,40 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+/// Edges of a minimum spanning forest with their total weight.
+/// Disconnected graphs get one tree per connected component.
+#[derive(Debug, Clone)]
+pub struct SpanningTree<V, E> {
+    /// Chosen edges as `(from, to, weight)`, in the order the algorithm picked them
+    pub edges: Vec<(V, V, E)>,
+    pub total_weight: E,
+}
+
+impl<V, E> SpanningTree<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    /// Convert chosen id-level edges into vertex-keyed ones
+    pub(crate) fn from_ids(graph: &Graph<V, E>, chosen: Vec<(VertexId, VertexId, E)>) -> Self {
+        let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+
+        Self {
+            total_weight: chosen.iter().fold(E::zero(), |total, &(_, _, w)| total + w),
+            edges: chosen.into_iter().map(|(u, v, w)| (vertex(u), vertex(v), w)).collect(),
+        }
+    }
+}
+
+/// Collect every edge once as undirected, dropping self-loops.
+/// Directed edges are treated as if they went both ways.
+pub(crate) fn undirected_edges<G: GraphView>(graph: &G) -> Vec<(VertexId, VertexId, G::Weight)> {
+    let directed = graph.is_directed();
+
+    (0..graph.node_count())
+        .flat_map(|u| graph.adjacent(u).map(move |(v, w)| (u, v, w)))
+        .filter(|&(u, v, _)| if directed { u != v } else { u < v })
+        .collect()
+}
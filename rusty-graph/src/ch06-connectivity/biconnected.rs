@@ -0,0 +1,225 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::mst::spanning_tree::undirected_edges;
+use crate::representations::{EdgeDirection, Graph, GraphView, RepresentationKind, VertexId, Weight};
+
+use super::DisjointSet;
+
+/// Single points of failure of a graph, from one low-link pass
+///
+/// Edge direction is ignored. Parallel edges count separately, so a doubled
+/// edge is never a bridge; self-loops are ignored.
+#[derive(Debug, Clone)]
+pub struct Biconnectivity<V> {
+    /// Cut vertices: removing one disconnects its component. In id order.
+    pub articulation_points: Vec<V>,
+    /// Edges whose removal disconnects their component, each once as `(parent, child)` of the DFS tree
+    pub bridges: Vec<(V, V)>,
+    /// Biconnected components: maximal sets of vertices with no cut vertex
+    /// inside. Cut vertices belong to several blocks; an isolated vertex is a
+    /// block by itself.
+    pub blocks: Vec<Vec<V>>,
+    /// Components left once every bridge is removed, ordered by their lowest vertex id
+    pub two_edge_components: Vec<Vec<V>>,
+}
+
+/// A vertex of the block-cut tree
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BlockCutNode<V> {
+    /// Index into `Biconnectivity::blocks`
+    Block(usize),
+    /// An articulation point
+    Cut(V),
+}
+
+impl<V> Biconnectivity<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// Build the block-cut tree: one vertex per block and per articulation
+    /// point, with an edge whenever the cut vertex lies in the block. It is a
+    /// forest with one tree per connected component.
+    pub fn block_cut_tree(&self) -> Graph<BlockCutNode<V>> {
+        let mut tree = Graph::new(EdgeDirection::Undirected, RepresentationKind::AdjacencyList);
+        for cut in &self.articulation_points {
+            tree.add_vertex(BlockCutNode::Cut(cut.clone()));
+        }
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            tree.add_vertex(BlockCutNode::Block(index));
+            for v in block {
+                let cut = BlockCutNode::Cut(v.clone());
+                if tree.contains_vertex(&cut) {
+                    tree.add_edge(BlockCutNode::Block(index), cut, ());
+                }
+            }
+        }
+
+        tree
+    }
+}
+
+/// Find articulation points, bridges, blocks and 2-edge-connected components
+/// with Tarjan's low-link values, O(V + E), on an explicit DFS stack
+pub fn biconnectivity<V, E>(graph: &Graph<V, E>) -> Biconnectivity<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+    let ids = biconnectivity_ids(graph);
+
+    Biconnectivity {
+        articulation_points: ids.articulation_points.into_iter().map(vertex).collect(),
+        bridges: ids.bridges.into_iter().map(|(u, v)| (vertex(u), vertex(v))).collect(),
+        blocks: ids.blocks.into_iter().map(|block| block.into_iter().map(vertex).collect()).collect(),
+        two_edge_components: ids.two_edge_components
+            .into_iter()
+            .map(|component| component.into_iter().map(vertex).collect())
+            .collect(),
+    }
+}
+
+/// Id-level results of `biconnectivity_ids`
+pub(crate) struct BiconnectivityIds {
+    pub(crate) articulation_points: Vec<VertexId>,
+    pub(crate) bridges: Vec<(VertexId, VertexId)>,
+    pub(crate) blocks: Vec<Vec<VertexId>>,
+    pub(crate) two_edge_components: Vec<Vec<VertexId>>,
+}
+
+/// A vertex on the DFS stack, the edge it was entered by and its next incident edge
+struct Frame {
+    vertex: VertexId,
+    parent_edge: Option<usize>,
+    next: usize,
+}
+
+pub(crate) fn biconnectivity_ids<G: GraphView>(graph: &G) -> BiconnectivityIds {
+    let n = graph.node_count();
+    let edges = undirected_edges(graph);
+
+    // Edges are told apart by index, so only the tree edge itself is skipped
+    // when looking back at the parent, not its parallel copies
+    let mut incident = vec![Vec::new(); n];
+    for (index, &(u, v, _)) in edges.iter().enumerate() {
+        incident[u].push((v, index));
+        incident[v].push((u, index));
+    }
+
+    let mut discovered: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0; n];
+    let mut is_cut = vec![false; n];
+    let mut is_bridge = vec![false; edges.len()];
+    let mut bridges = Vec::new();
+    let mut blocks = Vec::new();
+    let mut edge_stack = Vec::new();
+    let mut in_block = vec![usize::MAX; n];
+    let mut time = 0;
+
+    for root in 0..n {
+        if discovered[root].is_some() {
+            continue;
+        }
+        discovered[root] = Some(time);
+        low[root] = time;
+        time += 1;
+
+        if incident[root].is_empty() {
+            blocks.push(vec![root]);
+            continue;
+        }
+
+        let mut root_children = 0;
+        let mut stack = vec![Frame { vertex: root, parent_edge: None, next: 0 }];
+        while let Some(frame) = stack.last_mut() {
+            let u = frame.vertex;
+            if let Some(&(v, edge)) = incident[u].get(frame.next) {
+                frame.next += 1;
+                if frame.parent_edge == Some(edge) {
+                    continue;
+                }
+                match discovered[v] {
+                    None => {
+                        discovered[v] = Some(time);
+                        low[v] = time;
+                        time += 1;
+                        edge_stack.push(edge);
+                        stack.push(Frame { vertex: v, parent_edge: Some(edge), next: 0 });
+                    }
+                    // Back edge to an ancestor; edges to descendants were pushed from below
+                    Some(d) if d < discovered[u].unwrap() => {
+                        low[u] = low[u].min(d);
+                        edge_stack.push(edge);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            let Frame { vertex: v, parent_edge, .. } = stack.pop().unwrap();
+            let Some(parent_edge) = parent_edge else { continue };
+            let u = stack.last().unwrap().vertex;
+            low[u] = low[u].min(low[v]);
+
+            if low[v] > discovered[u].unwrap() {
+                is_bridge[parent_edge] = true;
+                bridges.push((u, v));
+            }
+
+            // Nothing below v climbs above u: the edges since (u, v) form a block
+            if low[v] >= discovered[u].unwrap() {
+                if u == root {
+                    root_children += 1;
+                } else {
+                    is_cut[u] = true;
+                }
+
+                let block_id = blocks.len();
+                let mut block = Vec::new();
+                loop {
+                    let edge = edge_stack.pop().unwrap();
+                    let (a, b, _) = edges[edge];
+                    for w in [a, b] {
+                        if std::mem::replace(&mut in_block[w], block_id) != block_id {
+                            block.push(w);
+                        }
+                    }
+                    if edge == parent_edge {
+                        break;
+                    }
+                }
+                blocks.push(block);
+            }
+        }
+
+        if root_children > 1 {
+            is_cut[root] = true;
+        }
+    }
+
+    let mut components = DisjointSet::new(n);
+    for (index, &(u, v, _)) in edges.iter().enumerate() {
+        if !is_bridge[index] {
+            components.union(u, v);
+        }
+    }
+    let mut component_index = vec![usize::MAX; n];
+    let mut two_edge_components: Vec<Vec<VertexId>> = Vec::new();
+    for v in 0..n {
+        let root = components.find(v);
+        if component_index[root] == usize::MAX {
+            component_index[root] = two_edge_components.len();
+            two_edge_components.push(Vec::new());
+        }
+        two_edge_components[component_index[root]].push(v);
+    }
+
+    BiconnectivityIds {
+        articulation_points: (0..n).filter(|&v| is_cut[v]).collect(),
+        bridges,
+        blocks,
+        two_edge_components,
+    }
+}
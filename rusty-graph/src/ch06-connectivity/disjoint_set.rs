@@ -0,0 +1,200 @@
+/// Disjoint-set forest over `0..len` with union by size and path compression.
+/// `find` is iterative, so long parent chains cannot overflow the stack.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>, // Only meaningful for roots
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Create `len` singleton sets `0..len`
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Get the number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Find the representative of the set containing x
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Second pass: point the whole path at the root
+        let mut current = x;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merge the sets containing x and y, returning false if they were already one set
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x_root, mut y_root) = (self.find(x), self.find(y));
+        if x_root == y_root {
+            return false;
+        }
+
+        if self.size[x_root] < self.size[y_root] {
+            std::mem::swap(&mut x_root, &mut y_root);
+        }
+        self.parent[y_root] = x_root;
+        self.size[x_root] += self.size[y_root];
+        self.count -= 1;
+        true
+    }
+
+    /// Check whether x and y are in the same set
+    pub fn same(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Get the size of the set containing x
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// List every set, each sorted and ordered by its smallest element
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let roots: Vec<usize> = (0..self.len()).map(|x| self.find(x)).collect();
+        group_by_root(&roots)
+    }
+}
+
+/// Disjoint-set forest that can undo unions in LIFO order, for offline
+/// dynamic connectivity and divide-and-conquer over time.
+///
+/// Union by size without path compression keeps `find` at O(log n) while
+/// every union changes only two entries, which the undo stack records.
+#[derive(Debug, Clone)]
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+    history: Vec<(usize, usize)>, // (absorbed root, surviving root) per successful union
+}
+
+impl RollbackDisjointSet {
+    /// Create `len` singleton sets `0..len`
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+            history: Vec::new(),
+        }
+    }
+
+    /// Get the number of elements
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Get the number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Find the representative of the set containing x
+    pub fn find(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merge the sets containing x and y, returning false if they were already one set.
+    /// Only merges are recorded, so a failed union needs no undo.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x_root, mut y_root) = (self.find(x), self.find(y));
+        if x_root == y_root {
+            return false;
+        }
+
+        if self.size[x_root] < self.size[y_root] {
+            std::mem::swap(&mut x_root, &mut y_root);
+        }
+        self.parent[y_root] = x_root;
+        self.size[x_root] += self.size[y_root];
+        self.count -= 1;
+        self.history.push((y_root, x_root));
+        true
+    }
+
+    /// Check whether x and y are in the same set
+    pub fn same(&self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Get the size of the set containing x
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// List every set, each sorted and ordered by its smallest element
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let roots: Vec<usize> = (0..self.len()).map(|x| self.find(x)).collect();
+        group_by_root(&roots)
+    }
+
+    /// Get a checkpoint to pass to `rollback`, i.e. the number of recorded merges
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo the most recent merge, returning false if there is none
+    pub fn undo(&mut self) -> bool {
+        let Some((child, root)) = self.history.pop() else {
+            return false;
+        };
+        self.parent[child] = child;
+        self.size[root] -= self.size[child];
+        self.count += 1;
+        true
+    }
+
+    /// Undo merges until only `snapshot` of them remain
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
+
+/// Group elements by their root, ordering groups by smallest element
+fn group_by_root(roots: &[usize]) -> Vec<Vec<usize>> {
+    let mut index = vec![None; roots.len()];
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+
+    for (x, &root) in roots.iter().enumerate() {
+        let group = *index[root].get_or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(x);
+    }
+    groups
+}
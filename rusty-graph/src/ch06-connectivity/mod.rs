@@ -0,0 +1,7 @@
+pub mod disjoint_set;
+pub mod scc;
+pub mod biconnected;
+
+pub use biconnected::{Biconnectivity, BlockCutNode, biconnectivity};
+pub use disjoint_set::{DisjointSet, RollbackDisjointSet};
+pub use scc::{StronglyConnectedComponents, kosaraju_scc, tarjan_scc};
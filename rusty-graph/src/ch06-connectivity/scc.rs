@@ -0,0 +1,203 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{EdgeDirection, Graph, GraphView, RepresentationKind, VertexId, Weight};
+use crate::traversal::dfs;
+use crate::traversal::visitor::OrderRecorder;
+
+/// Strongly connected components of a directed graph.
+///
+/// Components are numbered in topological order of the condensation: an edge
+/// between two components always goes from a lower id to a higher one. Both
+/// algorithms therefore number the same graph identically.
+#[derive(Debug, Clone)]
+pub struct StronglyConnectedComponents<V> {
+    /// Component id of every vertex
+    pub component_of: HashMap<V, usize>,
+    /// Vertices of each component, indexed by component id
+    pub components: Vec<Vec<V>>,
+}
+
+impl<V> StronglyConnectedComponents<V>
+where
+    V: Eq + Hash + Clone + Debug,
+{
+    /// Build from per-vertex component ids in `0..count`
+    fn from_ids<E>(graph: &Graph<V, E>, ids: Vec<usize>, count: usize) -> Self
+    where
+        E: Weight,
+    {
+        let mut components = vec![Vec::new(); count];
+        let mut component_of = HashMap::with_capacity(ids.len());
+
+        for (vertex, component) in graph.vertices().zip(ids) {
+            components[component].push(vertex.clone());
+            component_of.insert(vertex.clone(), component);
+        }
+
+        Self { component_of, components }
+    }
+
+    /// Get the number of components
+    pub fn count(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Build the component DAG: one vertex per component id, and an edge
+    /// between two components whenever some edge of `graph` joins them
+    pub fn condensation<E>(&self, graph: &Graph<V, E>) -> Graph<usize>
+    where
+        E: Weight,
+    {
+        let mut dag = Graph::new(EdgeDirection::Directed, RepresentationKind::AdjacencyList);
+        for component in 0..self.count() {
+            dag.add_vertex(component);
+        }
+
+        let mut seen = HashSet::new();
+        for (from, to, _) in graph.edges() {
+            let edge = (self.component_of[from], self.component_of[to]);
+            if edge.0 != edge.1 && seen.insert(edge) {
+                dag.add_edge(edge.0, edge.1, ());
+            }
+        }
+
+        dag
+    }
+}
+
+/// Tarjan's SCC algorithm, O(V + E), with an explicit stack instead of recursion
+pub fn tarjan_scc<V, E>(graph: &Graph<V, E>) -> StronglyConnectedComponents<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let (ids, count) = tarjan_scc_ids(graph);
+    StronglyConnectedComponents::from_ids(graph, ids, count)
+}
+
+/// Kosaraju's SCC algorithm, O(V + E): a DFS for finishing order, then a
+/// second pass over the reversed edges in decreasing finishing time
+pub fn kosaraju_scc<V, E>(graph: &Graph<V, E>) -> StronglyConnectedComponents<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let (ids, count) = kosaraju_scc_ids(graph);
+    StronglyConnectedComponents::from_ids(graph, ids, count)
+}
+
+/// A vertex on Tarjan's call stack with its remaining edges
+struct Frame<I> {
+    vertex: VertexId,
+    edges: I,
+}
+
+/// Id-level Tarjan, returning the component id of every vertex and the number of components
+pub(crate) fn tarjan_scc_ids<G: GraphView>(graph: &G) -> (Vec<usize>, usize) {
+    let n = graph.node_count();
+    let mut index = vec![None; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut component = vec![usize::MAX; n];
+    let mut count = 0;
+    let mut next_index = 0;
+
+    for root in 0..n {
+        if index[root].is_some() {
+            continue;
+        }
+
+        let mut calls = Vec::new();
+        index[root] = Some(next_index);
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        calls.push(Frame { vertex: root, edges: graph.adjacent(root) });
+
+        while let Some(frame) = calls.last_mut() {
+            let u = frame.vertex;
+
+            if let Some((v, _)) = frame.edges.next() {
+                match index[v] {
+                    None => {
+                        index[v] = Some(next_index);
+                        low[v] = next_index;
+                        next_index += 1;
+                        stack.push(v);
+                        on_stack[v] = true;
+                        calls.push(Frame { vertex: v, edges: graph.adjacent(v) });
+                    }
+                    Some(i) if on_stack[v] => low[u] = low[u].min(i),
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(parent) = calls.last() {
+                low[parent.vertex] = low[parent.vertex].min(low[u]);
+            }
+
+            // u is the root of a component: pop it off the stack
+            if Some(low[u]) == index[u] {
+                loop {
+                    let w = stack.pop().unwrap();
+                    on_stack[w] = false;
+                    component[w] = count;
+                    if w == u {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    // Tarjan completes components in reverse topological order
+    for id in &mut component {
+        *id = count - 1 - *id;
+    }
+    (component, count)
+}
+
+/// Id-level Kosaraju, returning the component id of every vertex and the number of components
+pub(crate) fn kosaraju_scc_ids<G: GraphView>(graph: &G) -> (Vec<usize>, usize) {
+    let n = graph.node_count();
+
+    let mut recorder = OrderRecorder::default();
+    dfs(graph, 0..n, &mut recorder);
+
+    let mut incoming = vec![Vec::new(); n];
+    for u in 0..n {
+        for (v, _) in graph.adjacent(u) {
+            incoming[v].push(u);
+        }
+    }
+
+    // Decreasing finishing time visits source components first
+    let mut component = vec![usize::MAX; n];
+    let mut count = 0;
+    for &root in recorder.post.iter().rev() {
+        if component[root] != usize::MAX {
+            continue;
+        }
+
+        component[root] = count;
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            for &v in &incoming[u] {
+                if component[v] == usize::MAX {
+                    component[v] = count;
+                    stack.push(v);
+                }
+            }
+        }
+        count += 1;
+    }
+
+    (component, count)
+}
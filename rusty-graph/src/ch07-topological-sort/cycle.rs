@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+
+use crate::representations::{GraphView, VertexId};
+
+/// A directed cycle that rules out a topological order.
+/// Each vertex has an edge to the next one and the last vertex leads back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<V> {
+    pub cycle: Vec<V>,
+}
+
+impl<V: Debug> fmt::Display for Cycle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle:")?;
+        for v in self.cycle.iter().chain(self.cycle.first()) {
+            write!(f, " {:?}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl<V: Debug> Error for Cycle<V> {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Unvisited,
+    OnStack,
+    Done,
+}
+
+/// Iterative DFS over every vertex, returning the post-order or, on the first
+/// edge back into the current path, the cycle it closes. Edges are taken as
+/// reported by `adjacent`, so an undirected edge counts as a two-vertex cycle.
+pub(crate) fn postorder_or_cycle<G: GraphView>(graph: &G) -> Result<Vec<VertexId>, Vec<VertexId>> {
+    let n = graph.node_count();
+    let mut state = vec![State::Unvisited; n];
+    let mut postorder = Vec::with_capacity(n);
+
+    for root in 0..n {
+        if state[root] != State::Unvisited {
+            continue;
+        }
+
+        state[root] = State::OnStack;
+        let mut stack = vec![(root, graph.adjacent(root))];
+
+        while let Some((u, edges)) = stack.last_mut() {
+            let u = *u;
+            let Some((v, _)) = edges.next() else {
+                stack.pop();
+                state[u] = State::Done;
+                postorder.push(u);
+                continue;
+            };
+
+            match state[v] {
+                State::Unvisited => {
+                    state[v] = State::OnStack;
+                    stack.push((v, graph.adjacent(v)));
+                }
+                State::OnStack => {
+                    // The stack holds the current path, so the cycle is its tail from v
+                    let start = stack.iter().position(|(w, _)| *w == v).unwrap();
+                    return Err(stack[start..].iter().map(|(w, _)| *w).collect());
+                }
+                State::Done => {}
+            }
+        }
+    }
+
+    Ok(postorder)
+}
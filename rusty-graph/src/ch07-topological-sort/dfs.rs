@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, Weight};
+
+use super::cycle::{Cycle, postorder_or_cycle};
+
+/// Topological sort by reverse DFS post-order, O(V + E).
+/// Fails with the first cycle the search runs into.
+pub fn topological_sort_dfs<V, E>(graph: &Graph<V, E>) -> Result<Vec<V>, Cycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let vertex = |id| graph.vertex(id).unwrap().clone();
+
+    match postorder_or_cycle(graph) {
+        Ok(postorder) => Ok(postorder.into_iter().rev().map(vertex).collect()),
+        Err(cycle) => Err(Cycle { cycle: cycle.into_iter().map(vertex).collect() }),
+    }
+}
@@ -0,0 +1,131 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, VertexId, Weight};
+
+use super::cycle::{Cycle, postorder_or_cycle};
+
+/// Kahn's algorithm, O(V + E): repeatedly remove a vertex without incoming edges.
+/// Ties are taken in vertex insertion order.
+pub fn topological_sort<V, E>(graph: &Graph<V, E>) -> Result<Vec<V>, Cycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let mut in_degree = in_degrees(graph);
+    let mut queue: VecDeque<VertexId> = (0..graph.vertex_count())
+        .filter(|&v| in_degree[v] == 0)
+        .collect();
+    let mut order = Vec::with_capacity(graph.vertex_count());
+
+    while let Some(u) = queue.pop_front() {
+        order.push(graph.vertex(u).unwrap().clone());
+        for (v, _) in graph.adjacent(u) {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    finish(graph, order)
+}
+
+/// The lexicographically smallest topological order, O((V + E) log V).
+/// Always removes the smallest vertex without incoming edges.
+pub fn lexicographic_topological_sort<V, E>(graph: &Graph<V, E>) -> Result<Vec<V>, Cycle<V>>
+where
+    V: Eq + Hash + Ord + Clone + Debug,
+    E: Weight,
+{
+    let mut in_degree = in_degrees(graph);
+    let mut heap: BinaryHeap<Reverse<(&V, VertexId)>> = (0..graph.vertex_count())
+        .filter(|&v| in_degree[v] == 0)
+        .map(|v| Reverse((graph.vertex(v).unwrap(), v)))
+        .collect();
+    let mut order = Vec::with_capacity(graph.vertex_count());
+
+    while let Some(Reverse((vertex, u))) = heap.pop() {
+        order.push(vertex.clone());
+        for (v, _) in graph.adjacent(u) {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                heap.push(Reverse((graph.vertex(v).unwrap(), v)));
+            }
+        }
+    }
+
+    finish(graph, order)
+}
+
+/// Group vertices into layers that can run in parallel: layer 0 holds the
+/// sources, and every other vertex sits one layer after its latest predecessor.
+/// Within a layer vertices keep insertion order.
+pub fn topological_layers<V, E>(graph: &Graph<V, E>) -> Result<Vec<Vec<V>>, Cycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let mut in_degree = in_degrees(graph);
+    let mut layer: Vec<VertexId> = (0..graph.vertex_count())
+        .filter(|&v| in_degree[v] == 0)
+        .collect();
+    let mut layers = Vec::new();
+    let mut placed = 0;
+
+    while !layer.is_empty() {
+        let mut next = Vec::new();
+        for &u in &layer {
+            for (v, _) in graph.adjacent(u) {
+                in_degree[v] -= 1;
+                if in_degree[v] == 0 {
+                    next.push(v);
+                }
+            }
+        }
+        next.sort_unstable();
+
+        placed += layer.len();
+        layers.push(layer.into_iter().map(|v| graph.vertex(v).unwrap().clone()).collect());
+        layer = next;
+    }
+
+    if placed < graph.vertex_count() {
+        return Err(find_cycle(graph));
+    }
+    Ok(layers)
+}
+
+/// Count incoming edges of every vertex, parallel edges included
+fn in_degrees<G: GraphView>(graph: &G) -> Vec<usize> {
+    let mut in_degree = vec![0; graph.node_count()];
+    for u in 0..graph.node_count() {
+        for (v, _) in graph.adjacent(u) {
+            in_degree[v] += 1;
+        }
+    }
+    in_degree
+}
+
+/// Accept a complete order, or report a cycle among the vertices Kahn could not remove
+fn finish<V, E>(graph: &Graph<V, E>, order: Vec<V>) -> Result<Vec<V>, Cycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    if order.len() < graph.vertex_count() {
+        return Err(find_cycle(graph));
+    }
+    Ok(order)
+}
+
+fn find_cycle<V, E>(graph: &Graph<V, E>) -> Cycle<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let cycle = postorder_or_cycle(graph).expect_err("Kahn's algorithm stalled without a cycle");
+    Cycle { cycle: cycle.into_iter().map(|v| graph.vertex(v).unwrap().clone()).collect() }
+}
@@ -0,0 +1,7 @@
+pub mod cycle;
+pub mod kahn;
+pub mod dfs;
+
+pub use cycle::Cycle;
+pub use dfs::topological_sort_dfs;
+pub use kahn::{lexicographic_topological_sort, topological_layers, topological_sort};
@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, VertexId, Weight};
+
+/// A 2-coloring: every edge joins a vertex of `left` to one of `right`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bipartition<V> {
+    pub left: Vec<V>,
+    pub right: Vec<V>,
+}
+
+/// A cycle of odd length, which proves a graph is not bipartite.
+/// Consecutive vertices are adjacent and the last vertex is adjacent to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OddCycle<V> {
+    pub cycle: Vec<V>,
+}
+
+impl<V: Debug> fmt::Display for OddCycle<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "odd cycle:")?;
+        for v in self.cycle.iter().chain(self.cycle.first()) {
+            write!(f, " {:?}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl<V: Debug> Error for OddCycle<V> {}
+
+/// Split the vertices into two sides by BFS 2-coloring, O(V + E).
+///
+/// Edge direction is ignored. The first vertex of every connected component
+/// goes to `left`, and isolated vertices end up there as well.
+pub fn bipartition<V, E>(graph: &Graph<V, E>) -> Result<Bipartition<V>, OddCycle<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+
+    match two_coloring(graph) {
+        Ok(is_right) => {
+            let (right, left): (Vec<VertexId>, Vec<VertexId>) =
+                (0..graph.vertex_count()).partition(|&v| is_right[v]);
+            Ok(Bipartition {
+                left: left.into_iter().map(vertex).collect(),
+                right: right.into_iter().map(vertex).collect(),
+            })
+        }
+        Err(cycle) => Err(OddCycle { cycle: cycle.into_iter().map(vertex).collect() }),
+    }
+}
+
+/// Check whether a graph is bipartite
+pub fn is_bipartite<V, E>(graph: &Graph<V, E>) -> bool
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    two_coloring(graph).is_ok()
+}
+
+/// Id-level BFS 2-coloring over the undirected version of the graph,
+/// returning which vertices are on the right or the ids of an odd cycle
+pub(crate) fn two_coloring<G: GraphView>(graph: &G) -> Result<Vec<bool>, Vec<VertexId>> {
+    let n = graph.node_count();
+    let mut neighbors = vec![Vec::new(); n];
+    for u in 0..n {
+        for (v, _) in graph.adjacent(u) {
+            neighbors[u].push(v);
+            neighbors[v].push(u);
+        }
+    }
+
+    let mut depth: Vec<Option<usize>> = vec![None; n];
+    let mut parent = vec![None; n];
+
+    for root in 0..n {
+        if depth[root].is_some() {
+            continue;
+        }
+        depth[root] = Some(0);
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(u) = queue.pop_front() {
+            for &v in &neighbors[u] {
+                match depth[v] {
+                    None => {
+                        depth[v] = Some(depth[u].unwrap() + 1);
+                        parent[v] = Some(u);
+                        queue.push_back(v);
+                    }
+                    Some(d) if d % 2 == depth[u].unwrap() % 2 => {
+                        return Err(odd_cycle(&parent, &depth, u, v));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Ok(depth.into_iter().map(|d| d.unwrap() % 2 == 1).collect())
+}
+
+/// Close the edge u-v between two same-colored vertices into a cycle
+/// through their lowest common ancestor in the BFS tree
+fn odd_cycle(
+    parent: &[Option<VertexId>],
+    depth: &[Option<usize>],
+    u: VertexId,
+    v: VertexId,
+) -> Vec<VertexId> {
+    // BFS edges span at most one level, so equal colors mean equal depths
+    debug_assert_eq!(depth[u], depth[v]);
+    let (mut a, mut b) = (u, v);
+    let (mut up, mut down) = (vec![a], vec![b]);
+
+    while a != b {
+        a = parent[a].unwrap();
+        b = parent[b].unwrap();
+        up.push(a);
+        down.push(b);
+    }
+
+    // up runs u..lca, down runs v..lca; drop the second lca and walk back to v
+    down.pop();
+    up.extend(down.into_iter().rev());
+    up
+}
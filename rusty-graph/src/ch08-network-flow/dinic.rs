@@ -0,0 +1,71 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight};
+
+use super::network::{MaxFlow, Residual, min, terminals};
+
+/// Dinic's maximum flow, O(V^2 * E): each phase builds BFS levels and then
+/// saturates the level graph with a blocking flow. Edge weights are capacities.
+/// Returns `None` if either terminal is missing or both are the same vertex.
+pub fn dinic<V, E>(graph: &Graph<V, E>, source: &V, sink: &V) -> Option<MaxFlow<V, E>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (s, t) = terminals(graph, source, sink)?;
+    let mut network = Residual::from_graph(graph);
+    let mut value = E::zero();
+
+    loop {
+        let mut level = network.levels(s);
+        if level[t].is_none() {
+            break;
+        }
+
+        // Blocking flow by iterative DFS; `next` is the current-arc pointer
+        let mut next = vec![0; network.node_count()];
+        let mut path = Vec::new();
+        let mut u = s;
+
+        loop {
+            if u == t {
+                let bottleneck = path.iter()
+                    .map(|&edge| network.capacity[edge])
+                    .reduce(min)
+                    .unwrap();
+                for &edge in &path {
+                    network.push(edge, bottleneck);
+                }
+                value = value + bottleneck;
+                path.clear();
+                u = s;
+                continue;
+            }
+
+            let admissible = network.adjacency[u][next[u]..].iter().position(|&edge| {
+                let v = network.to[edge];
+                network.has_room(edge) && level[v] == level[u].map(|l| l + 1)
+            });
+
+            match admissible {
+                Some(offset) => {
+                    next[u] += offset;
+                    let edge = network.adjacency[u][next[u]];
+                    path.push(edge);
+                    u = network.to[edge];
+                }
+                None if u == s => break,
+                None => {
+                    // Dead end: drop u from the level graph and retreat
+                    level[u] = None;
+                    let edge = path.pop().unwrap();
+                    u = network.from(edge);
+                    next[u] += 1;
+                }
+            }
+        }
+    }
+
+    Some(network.into_max_flow(graph, s, value))
+}
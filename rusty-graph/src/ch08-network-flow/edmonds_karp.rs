@@ -0,0 +1,56 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight};
+
+use super::network::{MaxFlow, Residual, min, terminals};
+
+/// Edmonds-Karp maximum flow, O(V * E^2): Ford-Fulkerson augmenting along
+/// shortest paths found by BFS. Edge weights are capacities.
+/// Returns `None` if either terminal is missing or both are the same vertex.
+pub fn edmonds_karp<V, E>(graph: &Graph<V, E>, source: &V, sink: &V) -> Option<MaxFlow<V, E>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (s, t) = terminals(graph, source, sink)?;
+    let mut network = Residual::from_graph(graph);
+    let mut value = E::zero();
+
+    loop {
+        // BFS recording the edge used to reach every vertex
+        let mut via = vec![None; network.node_count()];
+        let mut queue = VecDeque::from([s]);
+        while let Some(u) = queue.pop_front() {
+            for &edge in &network.adjacency[u] {
+                let v = network.to[edge];
+                if v != s && via[v].is_none() && network.has_room(edge) {
+                    via[v] = Some(edge);
+                    queue.push_back(v);
+                }
+            }
+        }
+        if via[t].is_none() {
+            break;
+        }
+
+        let mut path = Vec::new();
+        let mut v = t;
+        while let Some(edge) = via[v] {
+            path.push(edge);
+            v = network.from(edge);
+        }
+
+        let bottleneck = path.iter()
+            .map(|&edge| network.capacity[edge])
+            .reduce(min)
+            .unwrap();
+        for &edge in &path {
+            network.push(edge, bottleneck);
+        }
+        value = value + bottleneck;
+    }
+
+    Some(network.into_max_flow(graph, s, value))
+}
@@ -0,0 +1,154 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, VertexId, Weight};
+
+/// The two sides of a bipartite graph, each renumbered densely from 0,
+/// with the edges between them as left-to-right adjacency lists
+pub(crate) struct BipartiteSides {
+    pub left: Vec<VertexId>,  // Vertex id of every left index
+    pub right: Vec<VertexId>, // Vertex id of every right index
+    pub adjacency: Vec<Vec<(usize, usize)>>, // (right index, position of the edge in `edges`)
+}
+
+impl BipartiteSides {
+    /// Index both sides, keeping only edges between them along with their
+    /// weights. Direction is ignored.
+    pub fn new<V, E>(graph: &Graph<V, E>, left: &[V]) -> (Self, Vec<E>)
+    where
+        V: Eq + Hash + Clone + Debug,
+        E: Weight,
+    {
+        let n = graph.vertex_count();
+        let on_left: HashSet<VertexId> = left.iter().filter_map(|v| graph.vertex_id(v)).collect();
+
+        let (mut left_ids, mut right_ids) = (Vec::new(), Vec::new());
+        let index: Vec<usize> = (0..n)
+            .map(|v| {
+                let side = if on_left.contains(&v) { &mut left_ids } else { &mut right_ids };
+                side.push(v);
+                side.len() - 1
+            })
+            .collect();
+
+        let mut adjacency = vec![Vec::new(); left_ids.len()];
+        let mut weights = Vec::new();
+        for u in 0..n {
+            for (v, w) in graph.adjacent(u) {
+                let (l, r) = match (on_left.contains(&u), on_left.contains(&v)) {
+                    (true, false) => (u, v),
+                    (false, true) => (v, u),
+                    _ => continue,
+                };
+                adjacency[index[l]].push((index[r], weights.len()));
+                weights.push(w);
+            }
+        }
+
+        (Self { left: left_ids, right: right_ids, adjacency }, weights)
+    }
+}
+
+/// Hopcroft-Karp maximum cardinality matching, O(E * sqrt(V)).
+///
+/// The vertices in `left` form one side and all others the other side; edges
+/// within a side are ignored, as is edge direction. Returns the matched pairs
+/// as `(left, right)` in the order of `left`'s vertex ids.
+pub fn hopcroft_karp<V, E>(graph: &Graph<V, E>, left: &[V]) -> Vec<(V, V)>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let (sides, _) = BipartiteSides::new(graph, left);
+    let adjacency: Vec<Vec<usize>> = sides.adjacency.iter()
+        .map(|edges| edges.iter().map(|&(r, _)| r).collect())
+        .collect();
+
+    let mate = maximum_matching(&adjacency, sides.right.len());
+
+    mate.into_iter()
+        .enumerate()
+        .filter_map(|(l, r)| {
+            let r = r?;
+            let vertex = |id| graph.vertex(id).unwrap().clone();
+            Some((vertex(sides.left[l]), vertex(sides.right[r])))
+        })
+        .collect()
+}
+
+/// Id-level Hopcroft-Karp over left-to-right adjacency lists,
+/// returning the right partner of every left vertex
+pub(crate) fn maximum_matching(adjacency: &[Vec<usize>], right_count: usize) -> Vec<Option<usize>> {
+    let left_count = adjacency.len();
+    let mut mate_left: Vec<Option<usize>> = vec![None; left_count];
+    let mut mate_right: Vec<Option<usize>> = vec![None; right_count];
+
+    loop {
+        // BFS layers from all free left vertices along alternating paths
+        let mut layer: Vec<Option<usize>> = vec![None; left_count];
+        let mut queue: VecDeque<usize> = (0..left_count).filter(|&l| mate_left[l].is_none()).collect();
+        for &l in &queue {
+            layer[l] = Some(0);
+        }
+        // Layer of the left vertices adjacent to the nearest free right vertex;
+        // nothing past it is explored, so every augmenting path is a shortest one
+        let mut limit = None;
+
+        while let Some(l) = queue.pop_front() {
+            let depth = layer[l].unwrap();
+            if limit.is_some_and(|limit| depth > limit) {
+                break;
+            }
+            for &r in &adjacency[l] {
+                match mate_right[r] {
+                    None => limit = limit.or(Some(depth)),
+                    Some(next) if layer[next].is_none() && limit.is_none() => {
+                        layer[next] = Some(depth + 1);
+                        queue.push_back(next);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        let Some(limit) = limit else {
+            break;
+        };
+
+        // Vertex-disjoint shortest augmenting paths by iterative DFS with current-arc pointers
+        let mut next = vec![0; left_count];
+        for root in 0..left_count {
+            if mate_left[root].is_some() {
+                continue;
+            }
+
+            let mut path = vec![root];
+            while let Some(&l) = path.last() {
+                let Some(&r) = adjacency[l].get(next[l]) else {
+                    // Dead end: no later path may use l in this phase
+                    layer[l] = None;
+                    path.pop();
+                    if let Some(&previous) = path.last() {
+                        next[previous] += 1;
+                    }
+                    continue;
+                };
+
+                match mate_right[r] {
+                    None if layer[l] == Some(limit) => {
+                        for &l in &path {
+                            let r = adjacency[l][next[l]];
+                            mate_left[l] = Some(r);
+                            mate_right[r] = Some(l);
+                        }
+                        break;
+                    }
+                    Some(m) if layer[m] == layer[l].map(|d| d + 1) => path.push(m),
+                    _ => next[l] += 1,
+                }
+            }
+        }
+    }
+
+    mate_left
+}
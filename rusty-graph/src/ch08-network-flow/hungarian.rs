@@ -0,0 +1,134 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Neg;
+
+use crate::representations::{Graph, NumericWeight};
+
+use super::hopcroft_karp::BipartiteSides;
+
+/// A minimum-cost assignment
+#[derive(Debug, Clone)]
+pub struct Assignment<V, E> {
+    /// Assigned pairs as `(left, right, cost)`
+    pub pairs: Vec<(V, V, E)>,
+    pub total_cost: E,
+}
+
+/// Hungarian algorithm for minimum-cost assignment, O(n^2 * m) for sides of
+/// sizes n <= m.
+///
+/// The vertices in `left` form one side and all others the other side; edge
+/// weights are costs and missing edges are forbidden pairings. Every vertex of
+/// the smaller side is assigned. Returns `None` if that is impossible with the
+/// edges present. Costs must be signed because the dual potentials go negative;
+/// negate them to maximize instead.
+pub fn hungarian<V, E>(graph: &Graph<V, E>, left: &[V]) -> Option<Assignment<V, E>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight + Neg<Output = E>,
+{
+    let (sides, weights) = BipartiteSides::new(graph, left);
+
+    // Cheapest edge between every pair, rows being the smaller side
+    let transpose = sides.left.len() > sides.right.len();
+    let (rows, cols) = if transpose {
+        (sides.right.len(), sides.left.len())
+    } else {
+        (sides.left.len(), sides.right.len())
+    };
+    let mut cost: Vec<Vec<Option<E>>> = vec![vec![None; cols]; rows];
+    for (l, edges) in sides.adjacency.iter().enumerate() {
+        for &(r, edge) in edges {
+            let (i, j) = if transpose { (r, l) } else { (l, r) };
+            if cost[i][j].is_none_or(|c| weights[edge] < c) {
+                cost[i][j] = Some(weights[edge]);
+            }
+        }
+    }
+
+    let column_of = assign(&cost, cols)?;
+
+    let vertex = |id| graph.vertex(id).unwrap().clone();
+    let mut pairs = Vec::with_capacity(rows);
+    let mut total_cost = E::zero();
+    for (i, j) in column_of.into_iter().enumerate() {
+        let c = cost[i][j].unwrap();
+        let (l, r) = if transpose { (j, i) } else { (i, j) };
+        pairs.push((vertex(sides.left[l]), vertex(sides.right[r]), c));
+        total_cost = total_cost + c;
+    }
+
+    Some(Assignment { pairs, total_cost })
+}
+
+/// Shortest augmenting path Hungarian algorithm with row and column potentials.
+/// Assigns every row a distinct column, returning the column of each row.
+fn assign<W: NumericWeight>(cost: &[Vec<Option<W>>], cols: usize) -> Option<Vec<usize>> {
+    let rows = cost.len();
+    // Index 0 of the column arrays is a virtual column holding the row being added
+    let mut row_potential = vec![W::zero(); rows];
+    let mut col_potential = vec![W::zero(); cols + 1];
+    let mut row_of: Vec<Option<usize>> = vec![None; cols + 1];
+    let mut way = vec![0; cols + 1];
+
+    for row in 0..rows {
+        row_of[0] = Some(row);
+        let mut j0 = 0;
+        let mut slack: Vec<Option<W>> = vec![None; cols + 1];
+        let mut used = vec![false; cols + 1];
+
+        // Grow a Dijkstra-like tree of tight edges until a free column is reached
+        while let Some(i0) = row_of[j0] {
+            used[j0] = true;
+            let mut delta: Option<W> = None;
+            let mut j1 = 0;
+
+            for j in 1..=cols {
+                if used[j] {
+                    continue;
+                }
+                if let Some(c) = cost[i0][j - 1] {
+                    let reduced = c - row_potential[i0] - col_potential[j];
+                    if slack[j].is_none_or(|s| reduced < s) {
+                        slack[j] = Some(reduced);
+                        way[j] = j0;
+                    }
+                }
+                if let Some(s) = slack[j]
+                    && delta.is_none_or(|d| s < d)
+                {
+                    delta = Some(s);
+                    j1 = j;
+                }
+            }
+
+            // No column is reachable: this row cannot be assigned
+            let delta = delta?;
+            for j in 0..=cols {
+                if used[j] {
+                    let i = row_of[j].unwrap();
+                    row_potential[i] = row_potential[i] + delta;
+                    col_potential[j] = col_potential[j] - delta;
+                } else if let Some(s) = slack[j] {
+                    slack[j] = Some(s - delta);
+                }
+            }
+            j0 = j1;
+        }
+
+        // Flip the alternating path back to the virtual column
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut column_of = vec![0; rows];
+    for (j, row) in row_of.into_iter().enumerate().skip(1) {
+        if let Some(i) = row {
+            column_of[i] = j - 1;
+        }
+    }
+    Some(column_of)
+}
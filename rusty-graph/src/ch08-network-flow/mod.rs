@@ -0,0 +1,15 @@
+pub mod network;
+pub mod edmonds_karp;
+pub mod dinic;
+pub mod push_relabel;
+pub mod bipartite;
+pub mod hopcroft_karp;
+pub mod hungarian;
+
+pub use bipartite::{Bipartition, OddCycle, bipartition, is_bipartite};
+pub use dinic::dinic;
+pub use edmonds_karp::edmonds_karp;
+pub use hopcroft_karp::hopcroft_karp;
+pub use hungarian::{Assignment, hungarian};
+pub use network::MaxFlow;
+pub use push_relabel::push_relabel;
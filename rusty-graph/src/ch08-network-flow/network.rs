@@ -0,0 +1,146 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+/// Result of a maximum flow computation
+#[derive(Debug, Clone)]
+pub struct MaxFlow<V, E> {
+    /// Total flow from source to sink
+    pub value: E,
+    /// Flow on every edge as `(from, to, flow)`, in adjacency order
+    pub edge_flows: Vec<(V, V, E)>,
+    /// Vertices reachable from the source in the final residual network
+    pub source_side: HashSet<V>,
+    /// All other vertices, the sink among them
+    pub sink_side: HashSet<V>,
+    /// Saturated edges from the source side to the sink side, as `(from, to, capacity)`.
+    /// Their capacities add up to `value`.
+    pub cut_edges: Vec<(V, V, E)>,
+}
+
+/// Residual network with every edge stored next to its reverse:
+/// edge `2i` is the i-th input edge and `2i + 1` its zero-capacity twin
+#[derive(Debug, Clone)]
+pub(crate) struct Residual<W> {
+    pub adjacency: Vec<Vec<usize>>,
+    pub to: Vec<VertexId>,
+    pub capacity: Vec<W>, // Remaining residual capacity
+}
+
+impl<W: NumericWeight> Residual<W> {
+    /// Build from a graph's edges taken as capacities. Undirected edges
+    /// arrive mirrored and so give the same capacity in both directions.
+    pub fn from_graph<G: GraphView<Weight = W>>(graph: &G) -> Self {
+        let n = graph.node_count();
+        let mut network = Self {
+            adjacency: vec![Vec::new(); n],
+            to: Vec::new(),
+            capacity: Vec::new(),
+        };
+
+        for u in 0..n {
+            for (v, capacity) in graph.adjacent(u) {
+                network.add_edge(u, v, capacity);
+            }
+        }
+        network
+    }
+
+    fn add_edge(&mut self, from: VertexId, to: VertexId, capacity: W) {
+        self.adjacency[from].push(self.to.len());
+        self.to.push(to);
+        self.capacity.push(capacity);
+
+        self.adjacency[to].push(self.to.len());
+        self.to.push(from);
+        self.capacity.push(W::zero());
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Get the tail of an edge, which is the head of its twin
+    pub fn from(&self, edge: usize) -> VertexId {
+        self.to[edge ^ 1]
+    }
+
+    /// Push `amount` along an edge, making room on its twin
+    pub fn push(&mut self, edge: usize, amount: W) {
+        self.capacity[edge] = self.capacity[edge] - amount;
+        self.capacity[edge ^ 1] = self.capacity[edge ^ 1] + amount;
+    }
+
+    /// Check whether an edge can still carry flow
+    pub fn has_room(&self, edge: usize) -> bool {
+        self.capacity[edge] > W::zero()
+    }
+
+    /// BFS distances from `source` over edges with residual capacity
+    pub fn levels(&self, source: VertexId) -> Vec<Option<usize>> {
+        let mut level = vec![None; self.node_count()];
+        let mut queue = VecDeque::from([source]);
+        level[source] = Some(0);
+
+        while let Some(u) = queue.pop_front() {
+            for &edge in &self.adjacency[u] {
+                let v = self.to[edge];
+                if level[v].is_none() && self.has_room(edge) {
+                    level[v] = Some(level[u].unwrap() + 1);
+                    queue.push_back(v);
+                }
+            }
+        }
+        level
+    }
+
+    /// Read the flow and minimum cut off the final residual network
+    pub fn into_max_flow<V>(self, graph: &Graph<V, W>, source: VertexId, value: W) -> MaxFlow<V, W>
+    where
+        V: Eq + Hash + Clone + Debug,
+    {
+        let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+        let reachable = self.levels(source);
+        let on_source_side = |v: VertexId| reachable[v].is_some();
+
+        let mut edge_flows = Vec::with_capacity(self.to.len() / 2);
+        let mut cut_edges = Vec::new();
+        for edge in (0..self.to.len()).step_by(2) {
+            let (u, v) = (self.from(edge), self.to[edge]);
+            // The twin's capacity is exactly what has been pushed forward
+            let flow = self.capacity[edge ^ 1];
+            edge_flows.push((vertex(u), vertex(v), flow));
+            if on_source_side(u) && !on_source_side(v) {
+                cut_edges.push((vertex(u), vertex(v), flow));
+            }
+        }
+
+        let (source_side, sink_side) = (0..self.node_count()).partition(|&v| on_source_side(v));
+        let collect = |ids: Vec<VertexId>| ids.into_iter().map(vertex).collect();
+
+        MaxFlow {
+            value,
+            edge_flows,
+            source_side: collect(source_side),
+            sink_side: collect(sink_side),
+            cut_edges,
+        }
+    }
+}
+
+/// Look up source and sink ids, rejecting a missing or shared terminal
+pub(crate) fn terminals<V, E>(graph: &Graph<V, E>, source: &V, sink: &V) -> Option<(VertexId, VertexId)>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (s, t) = (graph.vertex_id(source)?, graph.vertex_id(sink)?);
+    (s != t).then_some((s, t))
+}
+
+/// The smaller of two weights
+pub(crate) fn min<W: PartialOrd>(a: W, b: W) -> W {
+    if b < a { b } else { a }
+}
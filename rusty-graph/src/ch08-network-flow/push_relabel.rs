@@ -0,0 +1,73 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight};
+
+use super::network::{MaxFlow, Residual, min, terminals};
+
+/// FIFO push-relabel maximum flow, O(V^3).
+///
+/// Floods the source's edges, then repeatedly pushes excess downhill and
+/// lifts vertices that cannot get rid of it. Excess that cannot reach the
+/// sink drains back to the source. Edge weights are capacities.
+/// Returns `None` if either terminal is missing or both are the same vertex.
+pub fn push_relabel<V, E>(graph: &Graph<V, E>, source: &V, sink: &V) -> Option<MaxFlow<V, E>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let (s, t) = terminals(graph, source, sink)?;
+    let mut network = Residual::from_graph(graph);
+    let n = network.node_count();
+
+    let mut height = vec![0; n];
+    let mut excess = vec![E::zero(); n];
+    let mut next = vec![0; n];
+    let mut active = VecDeque::new();
+    height[s] = n;
+
+    for i in 0..network.adjacency[s].len() {
+        let edge = network.adjacency[s][i];
+        let (v, amount) = (network.to[edge], network.capacity[edge]);
+        if amount > E::zero() {
+            network.push(edge, amount);
+            if v != t && v != s && excess[v] == E::zero() {
+                active.push_back(v);
+            }
+            excess[v] = excess[v] + amount;
+        }
+    }
+
+    while let Some(u) = active.pop_front() {
+        // Discharge u
+        while excess[u] > E::zero() {
+            if next[u] == network.adjacency[u].len() {
+                // Relabel to just above the lowest neighbour with room
+                height[u] = 1 + network.adjacency[u].iter()
+                    .filter(|&&edge| network.has_room(edge))
+                    .map(|&edge| height[network.to[edge]])
+                    .min()
+                    .unwrap();
+                next[u] = 0;
+                continue;
+            }
+
+            let edge = network.adjacency[u][next[u]];
+            let v = network.to[edge];
+            if network.has_room(edge) && height[u] == height[v] + 1 {
+                let amount = min(excess[u], network.capacity[edge]);
+                network.push(edge, amount);
+                excess[u] = excess[u] - amount;
+                if v != s && v != t && excess[v] == E::zero() {
+                    active.push_back(v);
+                }
+                excess[v] = excess[v] + amount;
+            } else {
+                next[u] += 1;
+            }
+        }
+    }
+
+    Some(network.into_max_flow(graph, s, excess[t]))
+}
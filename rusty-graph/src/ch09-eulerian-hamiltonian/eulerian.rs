@@ -0,0 +1,188 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::connectivity::DisjointSet;
+use crate::representations::{EdgeDirection, Graph, VertexId, Weight};
+
+/// Why a graph has no Eulerian path or circuit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EulerianError<V> {
+    /// The edges span several connected components, one vertex of each is listed.
+    /// Directed graphs only need to be connected when edge direction is ignored.
+    Disconnected(Vec<V>),
+    /// Undirected graph with odd-degree vertices: a path allows none or two,
+    /// a circuit allows none
+    OddDegree(Vec<V>),
+    /// Directed graph with vertices whose out-degree minus in-degree is not zero,
+    /// beyond the one +1 start and one -1 end a path allows
+    Unbalanced(Vec<(V, isize)>),
+}
+
+impl<V: Debug> fmt::Display for EulerianError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Disconnected(components) => {
+                write!(f, "edges span {} disconnected components, at {:?}", components.len(), components)
+            }
+            Self::OddDegree(vertices) => {
+                write!(f, "{} vertices have odd degree: {:?}", vertices.len(), vertices)
+            }
+            Self::Unbalanced(vertices) => {
+                write!(f, "out-degree minus in-degree is off at")?;
+                for (v, balance) in vertices {
+                    write!(f, " {:?} ({:+})", v, balance)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<V: Debug> Error for EulerianError<V> {}
+
+/// Find an Eulerian path with Hierholzer's algorithm, O(V + E): a walk that
+/// uses every edge exactly once. Prefers a circuit when one exists.
+///
+/// Parallel edges and self-loops are all traversed. The result lists
+/// `edge count + 1` vertices; a graph without edges yields its first vertex.
+pub fn eulerian_path<V, E>(graph: &Graph<V, E>) -> Result<Vec<V>, EulerianError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    find_walk(graph, false)
+}
+
+/// Find an Eulerian circuit with Hierholzer's algorithm, O(V + E): a closed
+/// walk that uses every edge exactly once, so the first and last vertex agree.
+pub fn eulerian_circuit<V, E>(graph: &Graph<V, E>) -> Result<Vec<V>, EulerianError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    find_walk(graph, true)
+}
+
+fn find_walk<V, E>(graph: &Graph<V, E>, closed: bool) -> Result<Vec<V>, EulerianError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let n = graph.vertex_count();
+    let directed = graph.direction() == EdgeDirection::Directed;
+    let edges = multigraph_edges(graph);
+    let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+
+    if edges.is_empty() {
+        return Ok((0..n.min(1)).map(vertex).collect());
+    }
+
+    // Every edge must be reachable, direction aside
+    let mut components = DisjointSet::new(n);
+    let mut degree = vec![0; n];
+    let mut balance = vec![0isize; n];
+    for &(u, v) in &edges {
+        components.union(u, v);
+        degree[u] += 1;
+        degree[v] += 1;
+        balance[u] += 1;
+        balance[v] -= 1;
+    }
+    let mut seen_root = vec![false; n];
+    let representatives: Vec<VertexId> = (0..n)
+        .filter(|&v| degree[v] > 0)
+        .filter(|&v| !std::mem::replace(&mut seen_root[components.find(v)], true))
+        .collect();
+    if representatives.len() > 1 {
+        return Err(EulerianError::Disconnected(representatives.into_iter().map(vertex).collect()));
+    }
+
+    let first_with_edges = (0..n).find(|&v| degree[v] > 0).unwrap();
+    let start = if directed {
+        let off: Vec<VertexId> = (0..n).filter(|&v| balance[v] != 0).collect();
+        let starts: Vec<VertexId> = off.iter().copied().filter(|&v| balance[v] == 1).collect();
+        let ends = off.iter().filter(|&&v| balance[v] == -1).count();
+
+        match off.len() {
+            0 => first_with_edges,
+            2 if !closed && starts.len() == 1 && ends == 1 => starts[0],
+            _ => {
+                return Err(EulerianError::Unbalanced(
+                    off.into_iter().map(|v| (vertex(v), balance[v])).collect(),
+                ));
+            }
+        }
+    } else {
+        let odd: Vec<VertexId> = (0..n).filter(|&v| degree[v] % 2 == 1).collect();
+        match odd.len() {
+            0 => first_with_edges,
+            2 if !closed => odd[0],
+            _ => return Err(EulerianError::OddDegree(odd.into_iter().map(vertex).collect())),
+        }
+    };
+
+    Ok(hierholzer(n, &edges, directed, start).into_iter().map(vertex).collect())
+}
+
+/// Every edge once as `(from, to)`, parallel edges included. Undirected graphs
+/// store each edge in both directions, and a self-loop twice over, so only
+/// half of those entries are kept.
+fn multigraph_edges<V, E>(graph: &Graph<V, E>) -> Vec<(VertexId, VertexId)>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let directed = graph.direction() == EdgeDirection::Directed;
+    let mut loop_mirrors = vec![false; graph.vertex_count()];
+
+    graph.edges()
+        .map(|(from, to, _)| (graph.vertex_id(from).unwrap(), graph.vertex_id(to).unwrap()))
+        .filter(|&(u, v)| {
+            if directed || u < v {
+                true
+            } else if u == v {
+                loop_mirrors[u] = !loop_mirrors[u];
+                loop_mirrors[u]
+            } else {
+                false
+            }
+        })
+        .collect()
+}
+
+/// Hierholzer's algorithm with an explicit stack: follow unused edges until
+/// stuck, then back up, emitting vertices in reverse walk order
+pub(crate) fn hierholzer(n: usize, edges: &[(VertexId, VertexId)], directed: bool, start: VertexId) -> Vec<VertexId> {
+    let mut incident = vec![Vec::new(); n];
+    for (index, &(u, v)) in edges.iter().enumerate() {
+        incident[u].push(index);
+        if !directed && u != v {
+            incident[v].push(index);
+        }
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut next = vec![0; n];
+    let mut stack = vec![start];
+    let mut walk = Vec::with_capacity(edges.len() + 1);
+
+    while let Some(&u) = stack.last() {
+        while next[u] < incident[u].len() && used[incident[u][next[u]]] {
+            next[u] += 1;
+        }
+
+        match incident[u].get(next[u]) {
+            Some(&edge) => {
+                used[edge] = true;
+                let (a, b) = edges[edge];
+                stack.push(if a == u { b } else { a });
+            }
+            None => walk.push(stack.pop().unwrap()),
+        }
+    }
+
+    walk.reverse();
+    walk
+}
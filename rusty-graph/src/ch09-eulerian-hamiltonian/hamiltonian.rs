@@ -0,0 +1,508 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::connectivity::DisjointSet;
+use crate::representations::{Graph, GraphView, VertexId, Weight};
+
+/// Largest graph solved by the bitmask DP; bigger ones fall back to backtracking
+pub const HAMILTONIAN_DP_LIMIT: usize = 24;
+
+/// Result of a Hamiltonian path or cycle search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hamiltonian<V> {
+    /// Every vertex exactly once, in order; a cycle closes from the last back to the first
+    Found(Vec<V>),
+    /// No such path or cycle exists
+    Impossible(NoHamiltonian<V>),
+    /// The time budget ran out before the search finished
+    TimedOut,
+}
+
+/// Why a graph has no Hamiltonian path or cycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoHamiltonian<V> {
+    /// The graph falls apart into several components, one vertex of each is
+    /// listed. Directed graphs are split ignoring edge direction.
+    Disconnected(Vec<V>),
+    /// Vertices that can only start or end the walk, more than it allows.
+    /// A cycle allows none; a path allows two leaves of an undirected graph,
+    /// or one vertex without in-edges and one without out-edges.
+    DeadEnds(Vec<V>),
+    /// Exhaustive search found none
+    Exhausted,
+}
+
+impl<V: Debug> fmt::Display for NoHamiltonian<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Disconnected(components) => {
+                write!(f, "graph has {} disconnected components, at {:?}", components.len(), components)
+            }
+            Self::DeadEnds(vertices) => write!(f, "too many dead ends: {:?}", vertices),
+            Self::Exhausted => write!(f, "exhaustive search found none"),
+        }
+    }
+}
+
+impl<V: Debug> Error for NoHamiltonian<V> {}
+
+/// Find a Hamiltonian path, visiting every vertex exactly once, giving up after `budget`.
+///
+/// Cheap degree and connectivity checks run first. Graphs with up to
+/// `HAMILTONIAN_DP_LIMIT` vertices are then decided by a DP over vertex
+/// subsets, O(2^n n); larger ones by backtracking, which suits sparse graphs.
+/// Edges are followed in their direction; parallel edges and self-loops
+/// are ignored.
+pub fn hamiltonian_path<V, E>(graph: &Graph<V, E>, budget: Duration) -> Hamiltonian<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    find_hamiltonian(graph, false, budget)
+}
+
+/// Find a Hamiltonian cycle, giving up after `budget`. Works like
+/// `hamiltonian_path`; a single vertex counts as a cycle, while an undirected
+/// cycle needs at least three vertices so that no edge is used twice.
+pub fn hamiltonian_cycle<V, E>(graph: &Graph<V, E>, budget: Duration) -> Hamiltonian<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    find_hamiltonian(graph, true, budget)
+}
+
+fn find_hamiltonian<V, E>(graph: &Graph<V, E>, closed: bool, budget: Duration) -> Hamiltonian<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let n = graph.vertex_count();
+    let directed = graph.is_directed();
+    let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+    let vertices = |ids: Vec<VertexId>| ids.into_iter().map(vertex).collect();
+
+    if n <= 1 {
+        return Hamiltonian::Found(vertices((0..n).collect()));
+    }
+    if closed && !directed && n == 2 {
+        return Hamiltonian::Impossible(NoHamiltonian::Exhausted);
+    }
+
+    let (out, into) = simple_adjacency(graph);
+    if let Some(reason) = obstruction(&out, &into, directed, closed) {
+        return Hamiltonian::Impossible(match reason {
+            NoHamiltonian::Disconnected(ids) => NoHamiltonian::Disconnected(vertices(ids)),
+            NoHamiltonian::DeadEnds(ids) => NoHamiltonian::DeadEnds(vertices(ids)),
+            NoHamiltonian::Exhausted => NoHamiltonian::Exhausted,
+        });
+    }
+
+    let deadline = Instant::now().checked_add(budget);
+    let result = if n <= HAMILTONIAN_DP_LIMIT {
+        subset_dp(&out, &into, closed, deadline)
+    } else {
+        backtrack(&out, &into, directed, closed, deadline)
+    };
+
+    match result {
+        Some(Some(order)) => Hamiltonian::Found(vertices(order)),
+        Some(None) => Hamiltonian::Impossible(NoHamiltonian::Exhausted),
+        None => Hamiltonian::TimedOut,
+    }
+}
+
+/// Sorted out- and in-neighbour lists without parallel edges or self-loops.
+/// Both are the same for undirected graphs.
+fn simple_adjacency<G: GraphView>(graph: &G) -> (Vec<Vec<VertexId>>, Vec<Vec<VertexId>>) {
+    let n = graph.node_count();
+    let mut out: Vec<Vec<VertexId>> = (0..n)
+        .map(|u| graph.adjacent(u).map(|(v, _)| v).filter(|&v| v != u).collect())
+        .collect();
+    let mut into = vec![Vec::new(); n];
+    for (u, list) in out.iter().enumerate() {
+        for &v in list {
+            into[v].push(u);
+        }
+    }
+    for list in out.iter_mut().chain(&mut into) {
+        list.sort_unstable();
+        list.dedup();
+    }
+    (out, into)
+}
+
+/// Structural reasons that rule out a Hamiltonian path or cycle without searching
+fn obstruction(
+    out: &[Vec<VertexId>],
+    into: &[Vec<VertexId>],
+    directed: bool,
+    closed: bool,
+) -> Option<NoHamiltonian<VertexId>> {
+    let n = out.len();
+    let mut components = DisjointSet::new(n);
+    for (u, list) in out.iter().enumerate() {
+        for &v in list {
+            components.union(u, v);
+        }
+    }
+    if components.count() > 1 {
+        let mut seen_root = vec![false; n];
+        return Some(NoHamiltonian::Disconnected((0..n)
+            .filter(|&v| !std::mem::replace(&mut seen_root[components.find(v)], true))
+            .collect()));
+    }
+
+    let sources: Vec<VertexId> = (0..n).filter(|&v| into[v].is_empty()).collect();
+    let sinks: Vec<VertexId> = (0..n).filter(|&v| out[v].is_empty()).collect();
+    let leaves: Vec<VertexId> = (0..n).filter(|&v| out[v].len() < 2).collect();
+
+    let dead_ends = match (directed, closed) {
+        (false, true) => leaves,
+        (false, false) if leaves.len() > 2 => leaves,
+        (true, true) => {
+            let mut ends: Vec<VertexId> = sources.into_iter().chain(sinks).collect();
+            ends.sort_unstable();
+            ends.dedup();
+            ends
+        }
+        (true, false) if sources.len() > 1 => sources,
+        (true, false) if sinks.len() > 1 => sinks,
+        _ => Vec::new(),
+    };
+    (!dead_ends.is_empty()).then_some(NoHamiltonian::DeadEnds(dead_ends))
+}
+
+/// Bitmask DP: `ends[set]` has bit v when some path visits exactly `set` and
+/// stops at v. Cycles pin the path to start at vertex 0. Returns `None` once
+/// past the deadline.
+fn subset_dp(
+    out: &[Vec<VertexId>],
+    into: &[Vec<VertexId>],
+    closed: bool,
+    deadline: Option<Instant>,
+) -> Option<Option<Vec<VertexId>>> {
+    let n = out.len();
+    let full = (1usize << n) - 1;
+    let bits = |list: &Vec<VertexId>| list.iter().fold(0u32, |set, &v| set | 1 << v);
+    let predecessors: Vec<u32> = into.iter().map(bits).collect();
+    let mut ends = vec![0u32; full + 1];
+
+    for set in 1..=full {
+        if closed && set & 1 == 0 {
+            continue;
+        }
+        // Checking the clock on every set would dominate small graphs
+        if set.is_multiple_of(1 << 16) && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return None;
+        }
+
+        if set.is_power_of_two() {
+            if !closed || set == 1 {
+                ends[set] = set as u32;
+            }
+            continue;
+        }
+        let mut rest = set;
+        while rest != 0 {
+            let v = rest.trailing_zeros() as usize;
+            rest &= rest - 1;
+            if (!closed || v != 0) && predecessors[v] & ends[set ^ 1 << v] != 0 {
+                ends[set] |= 1 << v;
+            }
+        }
+    }
+
+    let mut candidates = ends[full];
+    if closed {
+        candidates &= bits(&into[0]);
+    }
+    if candidates == 0 {
+        return Some(None);
+    }
+
+    // Walk back through the table, taking the lowest valid predecessor each time
+    let mut v = candidates.trailing_zeros() as usize;
+    let mut set = full;
+    let mut order = vec![v];
+    while !set.is_power_of_two() {
+        set ^= 1 << v;
+        v = (predecessors[v] & ends[set]).trailing_zeros() as usize;
+        order.push(v);
+    }
+    order.reverse();
+    Some(Some(order))
+}
+
+/// Depth-first extension of a path from every useful start vertex.
+/// Returns `None` once past the deadline.
+fn backtrack(
+    out: &[Vec<VertexId>],
+    into: &[Vec<VertexId>],
+    directed: bool,
+    closed: bool,
+    deadline: Option<Instant>,
+) -> Option<Option<Vec<VertexId>>> {
+    let n = out.len();
+    let mut starts: Vec<VertexId> = (0..n).collect();
+    starts.sort_by_key(|&v| (into[v].len().min(out[v].len()), v));
+
+    // A cycle passes through any vertex, and a path is forced to start at a
+    // source; an undirected path may be walked from either end, so a leaf will do
+    let forced = if directed { into[starts[0]].is_empty() } else { out[starts[0]].len() < 2 };
+    if closed || forced {
+        starts.truncate(1);
+    }
+
+    let mut search = Search::new(out, into, directed, closed, starts[0], deadline);
+    for start in starts {
+        search.visit(start);
+        if search.extend()? {
+            return Some(Some(search.path));
+        }
+        search.leave();
+    }
+    Some(None)
+}
+
+/// What the search found at the end of the path
+enum Step {
+    /// Every vertex is on the path, closing it if a cycle is wanted
+    Done,
+    DeadEnd,
+    /// Unvisited neighbours to try next, most constrained first
+    Branch(Vec<VertexId>),
+}
+
+/// Path search state. Besides the path itself it keeps, for every vertex,
+/// how many of its neighbours are still unvisited, and tallies the unvisited
+/// vertices that are short of ways in or out, so that the degree checks of a
+/// step only look at the neighbours of the path's end.
+struct Search<'a> {
+    out: &'a [Vec<VertexId>],
+    into: &'a [Vec<VertexId>],
+    directed: bool,
+    closed: bool,
+    visited: Vec<bool>,
+    path: Vec<VertexId>,
+    open_out: Vec<usize>,  // Unvisited out-neighbours of every vertex
+    open_in: Vec<usize>,   // Unvisited in-neighbours of every vertex
+    leads_home: Vec<bool>, // Has an edge back to the start of a cycle
+    no_entry: usize,       // Unvisited vertices without unvisited in-neighbours
+    no_exit: usize,        // Unvisited vertices without a way out
+    one_exit: usize,       // Unvisited vertices with exactly one way out
+    reached: Vec<usize>,   // Stamp of the last reachability check to see each vertex
+    stamp: usize,
+    deadline: Option<Instant>,
+    steps: usize,
+}
+
+impl<'a> Search<'a> {
+    /// A search with nothing visited; `home` is the start of a cycle, unused for paths
+    fn new(
+        out: &'a [Vec<VertexId>],
+        into: &'a [Vec<VertexId>],
+        directed: bool,
+        closed: bool,
+        home: VertexId,
+        deadline: Option<Instant>,
+    ) -> Self {
+        let n = out.len();
+        let mut leads_home = vec![false; n];
+        if closed {
+            for &u in &into[home] {
+                leads_home[u] = true;
+            }
+        }
+
+        let mut search = Search {
+            out,
+            into,
+            directed,
+            closed,
+            visited: vec![false; n],
+            path: Vec::with_capacity(n),
+            open_out: out.iter().map(Vec::len).collect(),
+            open_in: into.iter().map(Vec::len).collect(),
+            leads_home,
+            no_entry: 0,
+            no_exit: 0,
+            one_exit: 0,
+            reached: vec![0; n],
+            stamp: 0,
+            deadline,
+            steps: 0,
+        };
+        for v in 0..n {
+            search.tally(v, true);
+        }
+        search
+    }
+
+    /// Extend the path from its start vertex to cover every vertex, with an
+    /// explicit stack of untried neighbours per path vertex. Returns whether
+    /// it succeeded, leaving only the start on the path if not, or `None`
+    /// once past the deadline.
+    fn extend(&mut self) -> Option<bool> {
+        let mut frames: Vec<(Vec<VertexId>, usize)> = Vec::new();
+        loop {
+            match self.step()? {
+                Step::Done => return Some(true),
+                Step::Branch(next) => frames.push((next, 0)),
+                Step::DeadEnd if frames.is_empty() => return Some(false),
+                Step::DeadEnd => self.leave(),
+            }
+
+            // Move on to the next untried neighbour, backing up past exhausted vertices
+            loop {
+                let (next, tried) = frames.last_mut().unwrap();
+                if let Some(&v) = next.get(*tried) {
+                    *tried += 1;
+                    self.visit(v);
+                    break;
+                }
+                frames.pop();
+                if frames.is_empty() {
+                    return Some(false);
+                }
+                self.leave();
+            }
+        }
+    }
+
+    /// Examine the newly reached end of the path
+    fn step(&mut self) -> Option<Step> {
+        let current = *self.path.last().unwrap();
+        if self.path.len() == self.visited.len() {
+            let closes = !self.closed || self.out[current].binary_search(&self.path[0]).is_ok();
+            return Some(if closes { Step::Done } else { Step::DeadEnd });
+        }
+
+        self.steps += 1;
+        if self.steps.is_multiple_of(1024) && self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return None;
+        }
+        if !self.feasible(current) {
+            return Some(Step::DeadEnd);
+        }
+
+        // Warnsdorff's rule: try the most constrained neighbour first
+        let mut next: Vec<VertexId> = self.out[current].iter().copied().filter(|&v| !self.visited[v]).collect();
+        next.sort_by_key(|&v| (self.open_out[v], v));
+        Some(Step::Branch(next))
+    }
+
+    /// Append `v` to the path
+    fn visit(&mut self, v: VertexId) {
+        self.tally(v, false);
+        self.visited[v] = true;
+        self.path.push(v);
+        self.close_off(v, true);
+    }
+
+    /// Take the last vertex off the path
+    fn leave(&mut self) {
+        let v = self.path.pop().unwrap();
+        self.close_off(v, false);
+        self.visited[v] = false;
+        self.tally(v, true);
+    }
+
+    /// Update the unvisited neighbour counts of the neighbours of `v` as it
+    /// gets visited, or unvisited again
+    fn close_off(&mut self, v: VertexId, closing: bool) {
+        let (out, into) = (self.out, self.into);
+        for &w in &out[v] {
+            self.tally(w, false);
+            if closing { self.open_in[w] -= 1 } else { self.open_in[w] += 1 }
+            self.tally(w, true);
+        }
+        for &u in &into[v] {
+            self.tally(u, false);
+            if closing { self.open_out[u] -= 1 } else { self.open_out[u] += 1 }
+            self.tally(u, true);
+        }
+    }
+
+    /// Ways out of `v` through unvisited vertices, or back to the start of a cycle
+    fn exits(&self, v: VertexId) -> usize {
+        self.open_out[v] + usize::from(self.leads_home[v])
+    }
+
+    /// Add or remove an unvisited `v` to or from the tallies of vertices short of ways in or out
+    fn tally(&mut self, v: VertexId, add: bool) {
+        if self.visited[v] {
+            return;
+        }
+        let exits = self.exits(v);
+        for (count, short) in [
+            (&mut self.no_entry, self.open_in[v] == 0),
+            (&mut self.no_exit, exits == 0),
+            (&mut self.one_exit, exits == 1),
+        ] {
+            if short {
+                if add { *count += 1 } else { *count -= 1 }
+            }
+        }
+    }
+
+    /// Prune when some unvisited vertex can no longer be entered or left, or
+    /// cannot be reached from the end of the path through unvisited vertices
+    fn feasible(&mut self, current: VertexId) -> bool {
+        let start = self.path[0];
+        let open_next = || self.out[current].iter().copied().filter(|&w| !self.visited[w]);
+
+        // Every unvisited vertex needs a way in and, unless it ends a path, a way out.
+        // In undirected graphs those must be two different neighbours. The end
+        // of the path is a way in to its unvisited neighbours.
+        let path_ends = usize::from(!self.closed);
+        if self.directed {
+            if self.no_entry > open_next().filter(|&w| self.open_in[w] == 0).count() || self.no_exit > path_ends {
+                return false;
+            }
+        } else {
+            let (mut isolated, mut stuck) = (self.no_exit, self.no_exit + self.one_exit);
+            // The start of a cycle already counts as a way out
+            if !(self.closed && current == start) {
+                for w in open_next() {
+                    match self.exits(w) {
+                        0 => isolated -= 1,
+                        1 => stuck -= 1,
+                        _ => {}
+                    }
+                }
+            }
+            if isolated > 0 || stuck > path_ends {
+                return false;
+            }
+        }
+
+        // A cycle must still be able to return to its start
+        if self.closed && current != start && self.open_in[start] == 0 {
+            return false;
+        }
+
+        // If the path came from a vertex whose only unvisited neighbour was
+        // `current`, everything that vertex reached is still reached from here
+        if self.path.len() >= 2 && self.open_out[self.path[self.path.len() - 2]] == 0 {
+            return true;
+        }
+
+        self.stamp += 1;
+        let mut stack = vec![current];
+        let mut count = 0;
+        while let Some(u) = stack.pop() {
+            for &v in &self.out[u] {
+                if !self.visited[v] && self.reached[v] != self.stamp {
+                    self.reached[v] = self.stamp;
+                    count += 1;
+                    stack.push(v);
+                }
+            }
+        }
+        count == self.visited.len() - self.path.len()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod eulerian;
+pub mod hamiltonian;
+
+pub use eulerian::{EulerianError, eulerian_circuit, eulerian_path};
+pub use hamiltonian::{HAMILTONIAN_DP_LIMIT, Hamiltonian, NoHamiltonian, hamiltonian_cycle, hamiltonian_path};
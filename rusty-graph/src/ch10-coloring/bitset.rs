@@ -0,0 +1,77 @@
+use crate::representations::VertexId;
+
+/// Fixed-size set of vertex ids packed into 64-bit words, so that set
+/// intersections on dense graphs take n / 64 word operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Create an empty set for ids `0..len`
+    pub fn new(len: usize) -> Self {
+        Self { words: vec![0; len.div_ceil(64)] }
+    }
+
+    /// Build adjacency rows from neighbour lists
+    pub fn rows(neighbors: &[Vec<VertexId>]) -> Vec<BitSet> {
+        neighbors.iter()
+            .map(|list| {
+                let mut row = BitSet::new(neighbors.len());
+                for &v in list {
+                    row.insert(v);
+                }
+                row
+            })
+            .collect()
+    }
+
+    pub fn insert(&mut self, v: VertexId) {
+        self.words[v / 64] |= 1 << (v % 64);
+    }
+
+    pub fn remove(&mut self, v: VertexId) {
+        self.words[v / 64] &= !(1 << (v % 64));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Get the smallest element
+    pub fn first(&self) -> Option<VertexId> {
+        self.words.iter()
+            .position(|&w| w != 0)
+            .map(|i| i * 64 + self.words[i].trailing_zeros() as usize)
+    }
+
+    /// Elements in both sets
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        BitSet { words: self.words.iter().zip(&other.words).map(|(a, b)| a & b).collect() }
+    }
+
+    /// Elements of self that are not in other
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        BitSet { words: self.words.iter().zip(&other.words).map(|(a, b)| a & !b).collect() }
+    }
+
+    /// Size of the intersection, without building it
+    pub fn intersection_len(&self, other: &BitSet) -> usize {
+        self.words.iter().zip(&other.words).map(|(a, b)| (a & b).count_ones() as usize).sum()
+    }
+
+    /// Iterate over the elements in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = VertexId> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
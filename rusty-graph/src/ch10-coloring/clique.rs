@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, VertexId, Weight};
+
+use super::bitset::BitSet;
+use super::simple::{degeneracy_order, simple_neighbors};
+
+/// A Bron-Kerbosch call: grow `clique` with vertices of `candidates`,
+/// where `excluded` holds vertices whose cliques were already reported
+struct Frame {
+    clique: Vec<VertexId>,
+    candidates: BitSet,
+    excluded: BitSet,
+    branches: Vec<VertexId>, // Candidates outside the pivot's neighbourhood, still to try
+}
+
+/// Lazy iterator over the maximal cliques of a graph, see [`maximal_cliques`]
+pub struct MaximalCliques<'a, V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    graph: &'a Graph<V, E>,
+    rows: Vec<BitSet>,
+    order: Vec<VertexId>,
+    position: Vec<usize>,
+    next_root: usize,
+    stack: Vec<Frame>,
+}
+
+/// Enumerate all maximal cliques with Bron-Kerbosch, using Tomita pivoting
+/// inside the top-level loop over a degeneracy ordering. Runs in
+/// O(d * n * 3^(d / 3)) for degeneracy d, and yields cliques one at a time.
+///
+/// Edge direction is ignored and self-loops are skipped. Adjacency is held as
+/// bitset rows, so dense graphs of a thousand vertices stay practical.
+pub fn maximal_cliques<V, E>(graph: &Graph<V, E>) -> MaximalCliques<'_, V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let neighbors = simple_neighbors(graph);
+    let (order, _) = degeneracy_order(&neighbors);
+    let mut position = vec![0; order.len()];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+
+    MaximalCliques {
+        graph,
+        rows: BitSet::rows(&neighbors),
+        order,
+        position,
+        next_root: 0,
+        stack: Vec::new(),
+    }
+}
+
+impl<V, E> MaximalCliques<'_, V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    /// Start a call, or report `clique` right away if it cannot grow
+    fn open(&mut self, clique: Vec<VertexId>, candidates: BitSet, excluded: BitSet) -> Option<Vec<VertexId>> {
+        if candidates.is_empty() {
+            return excluded.is_empty().then_some(clique);
+        }
+
+        // Pivot on the vertex covering most candidates, so only the rest need branches
+        let pivot = candidates.iter()
+            .chain(excluded.iter())
+            .max_by_key(|&u| candidates.intersection_len(&self.rows[u]))
+            .unwrap();
+        let branches = candidates.difference(&self.rows[pivot]).iter().collect();
+
+        self.stack.push(Frame { clique, candidates, excluded, branches });
+        None
+    }
+
+    fn to_vertices(&self, clique: Vec<VertexId>) -> Vec<V> {
+        clique.into_iter().map(|v| self.graph.vertex(v).unwrap().clone()).collect()
+    }
+}
+
+impl<V, E> Iterator for MaximalCliques<'_, V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    type Item = Vec<V>;
+
+    fn next(&mut self) -> Option<Vec<V>> {
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                // Root v: later neighbours may join, earlier ones were handled already
+                let &v = self.order.get(self.next_root)?;
+                self.next_root += 1;
+
+                let (mut later, mut earlier) = (BitSet::new(self.order.len()), BitSet::new(self.order.len()));
+                for u in self.rows[v].iter() {
+                    if self.position[u] > self.position[v] {
+                        later.insert(u);
+                    } else {
+                        earlier.insert(u);
+                    }
+                }
+                if let Some(clique) = self.open(vec![v], later, earlier) {
+                    return Some(self.to_vertices(clique));
+                }
+                continue;
+            };
+
+            let Some(v) = frame.branches.pop() else {
+                self.stack.pop();
+                continue;
+            };
+
+            let mut clique = frame.clique.clone();
+            clique.push(v);
+            let candidates = frame.candidates.intersection(&self.rows[v]);
+            let excluded = frame.excluded.intersection(&self.rows[v]);
+            frame.candidates.remove(v);
+            frame.excluded.insert(v);
+
+            if let Some(clique) = self.open(clique, candidates, excluded) {
+                return Some(self.to_vertices(clique));
+            }
+        }
+    }
+}
+
+/// Find a maximum clique by branch and bound, pruning with greedy coloring
+/// bounds (Tomita's MCQ). Exponential in the worst case.
+///
+/// Edge direction is ignored and self-loops are skipped. Returns an empty
+/// clique for an empty graph.
+pub fn maximum_clique<V, E>(graph: &Graph<V, E>) -> Vec<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let neighbors = simple_neighbors(graph);
+    let n = neighbors.len();
+
+    // Relabel by decreasing degree so the coloring bound is tight early on
+    let mut by_degree: Vec<VertexId> = (0..n).collect();
+    by_degree.sort_by_key(|&v| Reverse(neighbors[v].len()));
+    let mut label = vec![0; n];
+    for (i, &v) in by_degree.iter().enumerate() {
+        label[v] = i;
+    }
+    let relabeled: Vec<Vec<VertexId>> = by_degree.iter()
+        .map(|&v| neighbors[v].iter().map(|&w| label[w]).collect())
+        .collect();
+
+    let rows = BitSet::rows(&relabeled);
+    let mut all = BitSet::new(n);
+    for v in 0..n {
+        all.insert(v);
+    }
+
+    let mut best = Vec::new();
+    expand(&rows, &mut Vec::new(), all, &mut best);
+    best.into_iter().map(|v| graph.vertex(by_degree[v]).unwrap().clone()).collect()
+}
+
+/// Try to beat `best` by growing `clique` with vertices of `candidates`
+fn expand(rows: &[BitSet], clique: &mut Vec<VertexId>, mut candidates: BitSet, best: &mut Vec<VertexId>) {
+    let (order, colors) = color_classes(rows, &candidates);
+
+    // Highest colors first: a vertex with color c can add at most c more vertices
+    for (&v, &color) in order.iter().zip(&colors).rev() {
+        if clique.len() + color <= best.len() {
+            return;
+        }
+
+        clique.push(v);
+        let next = candidates.intersection(&rows[v]);
+        if next.is_empty() {
+            if clique.len() > best.len() {
+                best.clone_from(clique);
+            }
+        } else {
+            expand(rows, clique, next, best);
+        }
+        clique.pop();
+        candidates.remove(v);
+    }
+}
+
+/// Greedily split the candidates into independent sets, returning them
+/// ordered by color with each one's color number counted from 1
+fn color_classes(rows: &[BitSet], candidates: &BitSet) -> (Vec<VertexId>, Vec<usize>) {
+    let mut uncolored = candidates.clone();
+    let (mut order, mut colors) = (Vec::new(), Vec::new());
+    let mut color = 0;
+
+    while !uncolored.is_empty() {
+        color += 1;
+        let mut open = uncolored.clone();
+        while let Some(v) = open.first() {
+            order.push(v);
+            colors.push(color);
+            uncolored.remove(v);
+            open = open.difference(&rows[v]);
+            open.remove(v);
+        }
+    }
+
+    (order, colors)
+}
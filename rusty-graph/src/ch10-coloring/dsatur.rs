@@ -0,0 +1,43 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, Weight};
+
+use super::greedy::to_coloring;
+use super::simple::simple_neighbors;
+
+/// DSatur coloring, O((V + E) log V): always color the vertex whose neighbours
+/// already use the most distinct colors, breaking ties by degree, then by id.
+///
+/// Colors are `0..k`. Edge direction is ignored and self-loops are skipped.
+pub fn dsatur<V, E>(graph: &Graph<V, E>) -> HashMap<V, usize>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let neighbors = simple_neighbors(graph);
+    let n = neighbors.len();
+
+    let mut color = vec![None; n];
+    let mut neighbor_colors: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+    // Uncolored vertices keyed by (saturation, degree), largest last
+    let key = |v: usize, saturation: usize| (saturation, neighbors[v].len(), Reverse(v));
+    let mut queue: BTreeSet<_> = (0..n).map(|v| key(v, 0)).collect();
+
+    while let Some((_, _, Reverse(v))) = queue.pop_last() {
+        let c = (0..).find(|c| !neighbor_colors[v].contains(c)).unwrap();
+        color[v] = Some(c);
+
+        for &w in &neighbors[v] {
+            if color[w].is_none() && !neighbor_colors[w].contains(&c) {
+                queue.remove(&key(w, neighbor_colors[w].len()));
+                neighbor_colors[w].insert(c);
+                queue.insert(key(w, neighbor_colors[w].len()));
+            }
+        }
+    }
+
+    to_coloring(graph, color)
+}
@@ -0,0 +1,155 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::representations::{Graph, VertexId, Weight};
+
+use super::greedy::to_coloring;
+use super::simple::simple_neighbors;
+
+/// Result of an exact k-coloring search
+#[derive(Debug, Clone)]
+pub enum KColoring<V> {
+    /// A proper coloring with colors `0..k`
+    Colorable(HashMap<V, usize>),
+    /// The search space was exhausted: more than k colors are needed
+    NotColorable,
+    /// The time budget ran out before the search finished
+    TimedOut,
+}
+
+/// Decide whether the graph can be colored with `k` colors by backtracking,
+/// giving up after `budget`.
+///
+/// Branches on the uncolored vertex with the fewest remaining colors and
+/// only ever opens one new color at a time, which prunes color permutations.
+/// Edge direction is ignored and self-loops are skipped.
+pub fn k_coloring<V, E>(graph: &Graph<V, E>, k: usize, budget: Duration) -> KColoring<V>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let neighbors = simple_neighbors(graph);
+    let n = neighbors.len();
+    let mut search = Search {
+        neighbors: &neighbors,
+        k,
+        color: vec![None; n],
+        // blocked[v][c]: number of neighbours of v colored c
+        blocked: vec![vec![0; k]; n],
+        saturation: vec![0; n],
+        uncolored: BTreeSet::new(),
+        deadline: Instant::now().checked_add(budget),
+        steps: 0,
+    };
+    for v in 0..n {
+        search.uncolored.insert(search.rank(v));
+    }
+
+    match search.extend() {
+        Some(true) => KColoring::Colorable(to_coloring(graph, search.color)),
+        Some(false) => KColoring::NotColorable,
+        None => KColoring::TimedOut,
+    }
+}
+
+struct Search<'a> {
+    neighbors: &'a [Vec<VertexId>],
+    k: usize,
+    color: Vec<Option<usize>>,
+    blocked: Vec<Vec<usize>>,
+    saturation: Vec<usize>, // Distinct colors among the neighbours of every vertex
+    uncolored: BTreeSet<(Reverse<usize>, Reverse<usize>, VertexId)>, // By `rank`
+    deadline: Option<Instant>,
+    steps: usize,
+}
+
+impl Search<'_> {
+    /// Color every vertex, keeping the branches taken on an explicit stack of
+    /// (vertex, next color to try, colors opened before it) frames.
+    /// Returns whether it succeeded, or `None` once past the deadline.
+    fn extend(&mut self) -> Option<bool> {
+        let mut frames: Vec<(VertexId, usize, usize)> = Vec::new();
+        let mut used = 0;
+        loop {
+            if frames.len() == self.color.len() {
+                return Some(true);
+            }
+
+            // Checking the clock on every node would dominate small searches
+            self.steps += 1;
+            if self.steps.is_multiple_of(1024) && self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return None;
+            }
+
+            let (_, _, v) = *self.uncolored.first().unwrap();
+            frames.push((v, 0, used));
+
+            // Give the top vertex its next color, backing up past vertices that have none left
+            loop {
+                let (v, next, before) = frames.last_mut().unwrap();
+                let (v, before) = (*v, *before);
+                if self.color[v].is_some() {
+                    self.erase(v);
+                }
+                let choice = self.available(v, before).find(|&c| c >= *next);
+                if let Some(c) = choice {
+                    *next = c + 1;
+                    self.paint(v, c);
+                    used = before.max(c + 1);
+                    break;
+                }
+                frames.pop();
+                if frames.is_empty() {
+                    return Some(false);
+                }
+            }
+        }
+    }
+
+    /// Colors v may take: unblocked ones among those opened, plus one new color
+    fn available(&self, v: VertexId, used: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.k.min(used + 1)).filter(move |&c| self.blocked[v][c] == 0)
+    }
+
+    /// Order in which uncolored vertices are branched on. Every opened color
+    /// a neighbour takes is one fewer available, so the fewest remaining
+    /// colors means the most distinct neighbour colors; ties go to the
+    /// highest degree.
+    fn rank(&self, v: VertexId) -> (Reverse<usize>, Reverse<usize>, VertexId) {
+        (Reverse(self.saturation[v]), Reverse(self.neighbors[v].len()), v)
+    }
+
+    fn paint(&mut self, v: VertexId, c: usize) {
+        self.uncolored.remove(&self.rank(v));
+        for &w in &self.neighbors[v] {
+            if self.blocked[w][c] == 0 {
+                self.saturate(w, true);
+            }
+            self.blocked[w][c] += 1;
+        }
+        self.color[v] = Some(c);
+    }
+
+    fn erase(&mut self, v: VertexId) {
+        let c = self.color[v].take().unwrap();
+        for &w in &self.neighbors[v] {
+            self.blocked[w][c] -= 1;
+            if self.blocked[w][c] == 0 {
+                self.saturate(w, false);
+            }
+        }
+        self.uncolored.insert(self.rank(v));
+    }
+
+    /// Count a neighbour color of `w` in or out, keeping `uncolored` in order
+    fn saturate(&mut self, w: VertexId, gained: bool) {
+        let queued = self.color[w].is_none() && self.uncolored.remove(&self.rank(w));
+        if gained { self.saturation[w] += 1 } else { self.saturation[w] -= 1 }
+        if queued {
+            self.uncolored.insert(self.rank(w));
+        }
+    }
+}
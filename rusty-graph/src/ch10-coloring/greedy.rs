@@ -0,0 +1,78 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, VertexId, Weight};
+
+use super::simple::{degeneracy_order, simple_neighbors};
+
+/// The order in which greedy coloring visits vertices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GreedyOrder {
+    /// Vertex insertion order
+    #[default]
+    Insertion,
+    /// Welsh-Powell: decreasing degree
+    LargestFirst,
+    /// Reverse of repeatedly removing a minimum-degree vertex, which uses at
+    /// most degeneracy + 1 colors
+    SmallestLast,
+}
+
+/// Greedy coloring, O(V + E) plus the cost of the ordering: every vertex
+/// takes the smallest color not used by an already colored neighbour.
+///
+/// Colors are `0..k`. Edge direction is ignored and self-loops are skipped.
+pub fn greedy_coloring<V, E>(graph: &Graph<V, E>, order: GreedyOrder) -> HashMap<V, usize>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let neighbors = simple_neighbors(graph);
+    let n = neighbors.len();
+
+    let sequence: Vec<VertexId> = match order {
+        GreedyOrder::Insertion => (0..n).collect(),
+        GreedyOrder::LargestFirst => {
+            let mut sequence: Vec<VertexId> = (0..n).collect();
+            sequence.sort_by_key(|&v| Reverse(neighbors[v].len()));
+            sequence
+        }
+        GreedyOrder::SmallestLast => {
+            let (mut sequence, _) = degeneracy_order(&neighbors);
+            sequence.reverse();
+            sequence
+        }
+    };
+
+    let mut color = vec![None; n];
+    let mut taken = vec![false; n + 1];
+    for v in sequence {
+        for &w in &neighbors[v] {
+            if let Some(c) = color[w] {
+                taken[c] = true;
+            }
+        }
+        color[v] = taken.iter().position(|&t| !t);
+        for &w in &neighbors[v] {
+            if let Some(c) = color[w] {
+                taken[c] = false;
+            }
+        }
+    }
+
+    to_coloring(graph, color)
+}
+
+/// Map complete per-id colors to vertices
+pub(crate) fn to_coloring<V, E>(graph: &Graph<V, E>, color: Vec<Option<usize>>) -> HashMap<V, usize>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    color.into_iter()
+        .enumerate()
+        .map(|(v, c)| (graph.vertex(v).unwrap().clone(), c.unwrap()))
+        .collect()
+}
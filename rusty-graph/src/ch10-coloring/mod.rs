@@ -0,0 +1,13 @@
+pub mod simple;
+pub mod bitset;
+pub mod greedy;
+pub mod dsatur;
+pub mod exact;
+pub mod validate;
+pub mod clique;
+
+pub use clique::{MaximalCliques, maximal_cliques, maximum_clique};
+pub use dsatur::dsatur;
+pub use exact::{KColoring, k_coloring};
+pub use greedy::{GreedyOrder, greedy_coloring};
+pub use validate::{ColoringError, color_count, validate_coloring};
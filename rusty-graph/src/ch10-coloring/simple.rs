@@ -0,0 +1,64 @@
+use crate::representations::{GraphView, VertexId};
+
+/// Neighbour lists of the underlying simple undirected graph: direction,
+/// parallel edges and self-loops dropped, each list sorted
+pub(crate) fn simple_neighbors<G: GraphView>(graph: &G) -> Vec<Vec<VertexId>> {
+    let n = graph.node_count();
+    let mut neighbors = vec![Vec::new(); n];
+    for u in 0..n {
+        for (v, _) in graph.adjacent(u) {
+            if u != v {
+                neighbors[u].push(v);
+                neighbors[v].push(u);
+            }
+        }
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbors
+}
+
+/// Repeatedly remove a vertex of minimum remaining degree, O(V + E) with
+/// bucket queues. Returns the removal order and the degeneracy, i.e. the
+/// largest degree seen at removal time.
+pub(crate) fn degeneracy_order(neighbors: &[Vec<VertexId>]) -> (Vec<VertexId>, usize) {
+    let n = neighbors.len();
+    let mut degree: Vec<usize> = neighbors.iter().map(Vec::len).collect();
+    let max_degree = degree.iter().copied().max().unwrap_or(0);
+    let mut buckets = vec![Vec::new(); max_degree + 1];
+    for v in 0..n {
+        buckets[degree[v]].push(v);
+    }
+
+    let mut removed = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut degeneracy = 0;
+    let mut low = 0;
+
+    while order.len() < n {
+        // Buckets hold stale entries for vertices whose degree has since dropped
+        let Some(v) = buckets[low].pop() else {
+            low += 1;
+            continue;
+        };
+        if removed[v] || degree[v] != low {
+            continue;
+        }
+
+        removed[v] = true;
+        order.push(v);
+        degeneracy = degeneracy.max(low);
+        for &w in &neighbors[v] {
+            if !removed[w] {
+                degree[w] -= 1;
+                buckets[degree[w]].push(w);
+            }
+        }
+        // A neighbour may now sit one bucket lower
+        low = low.saturating_sub(1);
+    }
+
+    (order, degeneracy)
+}
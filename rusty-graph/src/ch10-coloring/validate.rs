@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, Weight};
+
+/// Why a coloring is not proper
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColoringError<V> {
+    /// A vertex has no color
+    Uncolored(V),
+    /// Two adjacent vertices share a color
+    Conflict(V, V, usize),
+}
+
+impl<V: Debug> fmt::Display for ColoringError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uncolored(v) => write!(f, "{:?} has no color", v),
+            Self::Conflict(u, v, c) => write!(f, "{:?} and {:?} are adjacent but both have color {}", u, v, c),
+        }
+    }
+}
+
+impl<V: Debug> Error for ColoringError<V> {}
+
+/// Check that every vertex is colored and no edge joins two vertices of the
+/// same color. Self-loops are ignored, as by the coloring algorithms.
+pub fn validate_coloring<V, E>(graph: &Graph<V, E>, coloring: &HashMap<V, usize>) -> Result<(), ColoringError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    if let Some(v) = graph.vertices().find(|v| !coloring.contains_key(*v)) {
+        return Err(ColoringError::Uncolored(v.clone()));
+    }
+
+    for (u, v, _) in graph.edges() {
+        if u != v && coloring[u] == coloring[v] {
+            return Err(ColoringError::Conflict(u.clone(), v.clone(), coloring[u]));
+        }
+    }
+    Ok(())
+}
+
+/// Count the distinct colors a coloring uses
+pub fn color_count<V>(coloring: &HashMap<V, usize>) -> usize {
+    let mut colors: Vec<usize> = coloring.values().copied().collect();
+    colors.sort_unstable();
+    colors.dedup();
+    colors.len()
+}
@@ -0,0 +1,81 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Div;
+
+use crate::eulerian_hamiltonian::eulerian::hierholzer;
+use crate::representations::{Graph, NumericWeight, VertexId};
+
+use super::matching::min_weight_perfect_matching;
+use super::tour::{Distances, Tour, TspError};
+
+/// Christofides' 3/2-approximation for metric TSP, O(V^3).
+///
+/// Joins a minimum spanning tree with a minimum weight perfect matching on
+/// its odd-degree vertices, found exactly by the blossom algorithm, then
+/// walks an Eulerian circuit of the union and skips repeated vertices. The
+/// bound needs symmetric distances that obey the triangle inequality.
+///
+/// The weight type needs halving for the matching duals; integer weights
+/// stay exact. Reports `TspError::Overflow` if those duals, which may reach
+/// about twice the largest distance, cannot be added up in the weight type.
+pub fn christofides<V, E>(graph: &Graph<V, E>) -> Result<Tour<V, E>, TspError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight + Div<Output = E> + From<u8>,
+{
+    let distances = Distances::new(graph)?;
+    let n = distances.len();
+    if n <= 2 {
+        return Tour::from_ids(graph, &distances, (0..n).collect());
+    }
+
+    let mut edges = spanning_tree(&distances);
+    let mut degree = vec![0; n];
+    for &(u, v) in &edges {
+        degree[u] += 1;
+        degree[v] += 1;
+    }
+
+    // A tree always has an even number of odd-degree vertices
+    let odd: Vec<VertexId> = (0..n).filter(|&v| degree[v] % 2 == 1).collect();
+    let weights: Vec<Vec<E>> = odd.iter()
+        .map(|&u| odd.iter().map(|&v| distances.get(u, v)).collect())
+        .collect();
+    let mate = min_weight_perfect_matching(&weights).ok_or(TspError::Overflow)?;
+    edges.extend((0..odd.len())
+        .filter(|&i| i < mate[i])
+        .map(|i| (odd[i], odd[mate[i]])));
+
+    // Every degree is now even, so the union has an Eulerian circuit to shortcut
+    let mut visited = vec![false; n];
+    let order = hierholzer(n, &edges, false, 0)
+        .into_iter()
+        .filter(|&v| !std::mem::replace(&mut visited[v], true))
+        .collect();
+
+    Tour::from_ids(graph, &distances, order)
+}
+
+/// Prim's algorithm on the dense matrix, O(V^2), which beats a heap on complete graphs
+fn spanning_tree<E: NumericWeight>(distances: &Distances<E>) -> Vec<(VertexId, VertexId)> {
+    let n = distances.len();
+    let mut in_tree = vec![false; n];
+    let mut closest: Vec<(E, VertexId)> = (0..n).map(|v| (distances.get(0, v), 0)).collect();
+    let mut edges = Vec::with_capacity(n - 1);
+    in_tree[0] = true;
+
+    for _ in 1..n {
+        let v = (0..n)
+            .filter(|&v| !in_tree[v])
+            .reduce(|a, b| if closest[b].0 < closest[a].0 { b } else { a })
+            .unwrap();
+        in_tree[v] = true;
+        edges.push((closest[v].1, v));
+        for u in (0..n).filter(|&u| !in_tree[u]) {
+            if distances.get(v, u) < closest[u].0 {
+                closest[u] = (distances.get(v, u), v);
+            }
+        }
+    }
+    edges
+}
@@ -0,0 +1,87 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight, VertexId};
+
+use super::tour::{Distances, Tour, TspError};
+
+/// Largest graph `held_karp` accepts: the table has 2^(n-1) * (n-1) entries
+pub const HELD_KARP_LIMIT: usize = 20;
+
+/// Optimal tour by Held-Karp dynamic programming over subsets, O(2^n n^2)
+/// time and O(2^n n) memory.
+///
+/// `best[set][last]` is the cheapest path that starts at the first vertex,
+/// visits exactly `set` and ends at `last`. Distances may be asymmetric.
+/// Returns `TooLarge` above `HELD_KARP_LIMIT` vertices, and `Overflow` when
+/// every tour costs more than the weight type can hold.
+pub fn held_karp<V, E>(graph: &Graph<V, E>) -> Result<Tour<V, E>, TspError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let n = graph.vertex_count();
+    if n > HELD_KARP_LIMIT {
+        return Err(TspError::TooLarge { vertices: n, limit: HELD_KARP_LIMIT });
+    }
+    let distances = Distances::new(graph)?;
+    if n <= 1 {
+        return Tour::from_ids(graph, &distances, (0..n).collect());
+    }
+
+    // Vertex 0 is the fixed start; bit i of a set stands for vertex i + 1
+    let m = n - 1;
+    let full = (1usize << m) - 1;
+    let mut best = vec![E::infinity(); (full + 1) * m];
+    let mut previous = vec![0u8; (full + 1) * m];
+    for last in 0..m {
+        best[(1 << last) * m + last] = distances.get(0, last + 1);
+    }
+
+    for set in 1..=full {
+        for last in (0..m).filter(|&last| set & (1 << last) != 0) {
+            let cost = best[set * m + last];
+            if cost == E::infinity() {
+                continue;
+            }
+            for next in (0..m).filter(|&next| set & (1 << next) == 0) {
+                let Some(candidate) = cost.checked_add(distances.get(last + 1, next + 1)) else {
+                    continue;
+                };
+                let slot = (set | 1 << next) * m + next;
+                if candidate < best[slot] {
+                    best[slot] = candidate;
+                    previous[slot] = last as u8;
+                }
+            }
+        }
+    }
+
+    // Only states actually reached have a predecessor chain to rebuild the tour from
+    let closing = |last: usize| {
+        let cost = best[full * m + last];
+        if cost == E::infinity() {
+            return None;
+        }
+        cost.checked_add(distances.get(last + 1, 0)).filter(|&total| total != E::infinity())
+    };
+    let Some(mut last) = (0..m)
+        .filter(|&last| closing(last).is_some())
+        .reduce(|a, b| if closing(b) < closing(a) { b } else { a })
+    else {
+        return Err(TspError::Overflow);
+    };
+
+    let mut order: Vec<VertexId> = Vec::with_capacity(n);
+    let mut set = full;
+    while set != 0 {
+        order.push(last + 1);
+        let before = previous[set * m + last] as usize;
+        set &= !(1 << last);
+        last = before;
+    }
+    order.push(0);
+    order.reverse();
+
+    Tour::from_ids(graph, &distances, order)
+}
@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight, VertexId};
+
+use super::tour::{Distances, Tour, TspError, tour_ids};
+
+/// Longest run of consecutive vertices `or_opt` moves at once
+const OR_OPT_SEGMENT: usize = 3;
+
+/// Improve a tour with 2-opt moves until none helps: replace edges (a, b) and
+/// (c, d) with (a, c) and (b, d) by reversing the stretch from b to c.
+///
+/// Each pass is O(V^2). Reversal assumes symmetric distances, which is the
+/// case for undirected graphs. The result keeps the tour's first vertex.
+pub fn two_opt<V, E>(graph: &Graph<V, E>, tour: &Tour<V, E>) -> Result<Tour<V, E>, TspError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let mut order = tour_ids(graph, tour)?;
+    let distances = Distances::new(graph)?;
+    let n = order.len();
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(2) {
+            for j in i + 2..n {
+                // Both edges share a vertex when they wrap around
+                if i == 0 && j == n - 1 {
+                    continue;
+                }
+                let (a, b) = (order[i], order[i + 1]);
+                let (c, e) = (order[j], order[(j + 1) % n]);
+                if distances.shorter(&[(a, c), (b, e)], &[(a, b), (c, e)]) {
+                    order[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    Tour::from_ids(graph, &distances, order)
+}
+
+/// Improve a tour with Or-opt moves until none helps: take a run of up to
+/// three consecutive vertices and reinsert it, possibly reversed, between two
+/// other neighbouring vertices.
+///
+/// Each pass is O(V^2). Reversed runs assume symmetric distances. The result
+/// keeps the tour's first vertex.
+pub fn or_opt<V, E>(graph: &Graph<V, E>, tour: &Tour<V, E>) -> Result<Tour<V, E>, TspError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let mut order = tour_ids(graph, tour)?;
+    let distances = Distances::new(graph)?;
+    let n = order.len();
+    let first = order.first().copied();
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for len in 1..=OR_OPT_SEGMENT.min(n.saturating_sub(3)) {
+            for i in 0..n {
+                // The rest of the tour runs from the vertex after the run round to the one before it
+                let run: Vec<VertexId> = (0..len).map(|t| order[(i + t) % n]).collect();
+                let rest: Vec<VertexId> = (0..n - len).map(|t| order[(i + len + t) % n]).collect();
+                let (head, tail) = (run[0], run[len - 1]);
+                let (next, prev) = (rest[0], rest[n - len - 1]);
+
+                let mut best = None;
+                for k in 0..rest.len() - 1 {
+                    let (x, y) = (rest[k], rest[k + 1]);
+                    let old = [(prev, head), (tail, next), (x, y)];
+                    for reversed in [false, true] {
+                        let (into, out) = if reversed { (tail, head) } else { (head, tail) };
+                        if distances.shorter(&[(prev, next), (x, into), (out, y)], &old) {
+                            best = Some((k, reversed));
+                            break;
+                        }
+                    }
+                    if best.is_some() {
+                        break;
+                    }
+                }
+
+                if let Some((k, reversed)) = best {
+                    let mut run = run;
+                    if reversed {
+                        run.reverse();
+                    }
+                    order = rest[..=k].iter()
+                        .chain(&run)
+                        .chain(&rest[k + 1..])
+                        .copied()
+                        .collect();
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    if let Some(first) = first {
+        let at = order.iter().position(|&v| v == first).unwrap();
+        order.rotate_left(at);
+    }
+    Tour::from_ids(graph, &distances, order)
+}
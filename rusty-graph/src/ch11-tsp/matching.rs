@@ -0,0 +1,599 @@
+//! Maximum weight matching in general graphs by Edmonds' blossom algorithm
+//! with dual variables, O(n^3). Follows the structure of Van Rantwijk's
+//! well-known implementation: edges are addressed by endpoint numbers, where
+//! endpoint `2k` is the first vertex of edge k and `2k + 1` the second, so
+//! `p ^ 1` is the other end of the same edge.
+
+use std::ops::Div;
+
+use crate::representations::NumericWeight;
+
+const NONE: usize = usize::MAX;
+
+// Labels of top-level blossoms; BREADCRUMB marks blossoms visited by `scan_blossom`
+const FREE: u8 = 0;
+const OUTER: u8 = 1;
+const INNER: u8 = 2;
+const BREADCRUMB: u8 = 4;
+
+/// Minimum weight perfect matching of a complete graph with an even number
+/// of vertices, given as a symmetric weight matrix. Returns every vertex's
+/// mate, or `None` if the dual variables outgrow the weight type.
+pub(crate) fn min_weight_perfect_matching<W>(weights: &[Vec<W>]) -> Option<Vec<usize>>
+where
+    W: NumericWeight + Div<Output = W> + From<u8>,
+{
+    let n = weights.len();
+    let heaviest = weights.iter()
+        .flatten()
+        .copied()
+        .reduce(|a, b| if b > a { b } else { a })
+        .unwrap_or(W::zero());
+
+    // Heaviest-minus-weight keeps every weight non-negative, and a maximum
+    // cardinality matching of a complete graph is perfect, so maximizing the
+    // flipped weights minimizes the originals
+    let edges = weights.iter()
+        .enumerate()
+        .flat_map(|(u, row)| row.iter().enumerate().skip(u + 1).map(move |(v, &w)| (u, v, heaviest - w)))
+        .collect();
+
+    Blossoms::new(n, edges).solve()
+}
+
+struct Blossoms<W> {
+    n: usize,
+    edges: Vec<(usize, usize, W)>,
+    endpoint: Vec<usize>,
+    neighbor_ends: Vec<Vec<usize>>, // Remote endpoints of the edges at each vertex
+    mate: Vec<usize>,               // Remote endpoint of the matched edge, per vertex
+    label: Vec<u8>,
+    label_end: Vec<usize>,   // Endpoint through which a vertex or blossom got its label
+    in_blossom: Vec<usize>,  // Top-level blossom of every vertex
+    parent: Vec<usize>,      // Immediate parent blossom
+    children: Vec<Vec<usize>>,
+    base: Vec<usize>,
+    child_ends: Vec<Vec<usize>>, // Endpoints linking consecutive children of a blossom
+    best_edge: Vec<usize>,       // Least-slack edge to a different S-blossom
+    best_edges: Vec<Option<Vec<usize>>>,
+    unused: Vec<usize>,
+    dual: Vec<W>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+    two: W,
+}
+
+impl<W> Blossoms<W>
+where
+    W: NumericWeight + Div<Output = W> + From<u8>,
+{
+    fn new(n: usize, edges: Vec<(usize, usize, W)>) -> Self {
+        let heaviest = edges.iter()
+            .map(|e| e.2)
+            .reduce(|a, b| if b > a { b } else { a })
+            .unwrap_or(W::zero());
+
+        let mut endpoint = Vec::with_capacity(2 * edges.len());
+        let mut neighbor_ends = vec![Vec::new(); n];
+        for (k, &(u, v, _)) in edges.iter().enumerate() {
+            endpoint.push(u);
+            endpoint.push(v);
+            neighbor_ends[u].push(2 * k + 1);
+            neighbor_ends[v].push(2 * k);
+        }
+
+        let mut dual = vec![heaviest; n];
+        dual.extend(vec![W::zero(); n]);
+
+        Self {
+            n,
+            endpoint,
+            neighbor_ends,
+            mate: vec![NONE; n],
+            label: vec![FREE; 2 * n],
+            label_end: vec![NONE; 2 * n],
+            in_blossom: (0..n).collect(),
+            parent: vec![NONE; 2 * n],
+            children: vec![Vec::new(); 2 * n],
+            base: (0..n).chain(std::iter::repeat_n(NONE, n)).collect(),
+            child_ends: vec![Vec::new(); 2 * n],
+            best_edge: vec![NONE; 2 * n],
+            best_edges: vec![None; 2 * n],
+            unused: (n..2 * n).collect(),
+            dual,
+            allowed: vec![false; edges.len()],
+            queue: Vec::new(),
+            two: W::from(2),
+            edges,
+        }
+    }
+
+    /// Whether any two vertex duals, and so every slack, can be added without
+    /// overflowing. Initially the duals equal the heaviest weight, which
+    /// covers doubling an edge weight too.
+    fn duals_fit(&self) -> bool {
+        let largest = self.dual[..self.n].iter()
+            .copied()
+            .reduce(|a, b| if b > a { b } else { a })
+            .unwrap_or(W::zero());
+        largest.checked_add(largest).is_some()
+    }
+
+    fn slack(&self, k: usize) -> W {
+        let (u, v, w) = self.edges[k];
+        self.dual[u] + self.dual[v] - (w + w)
+    }
+
+    /// All vertices contained in a blossom
+    fn leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(b) = stack.pop() {
+            if b < self.n {
+                leaves.push(b);
+            } else {
+                stack.extend(self.children[b].iter().rev());
+            }
+        }
+        leaves
+    }
+
+    /// Label the top-level blossom of w, reached through endpoint p. An inner
+    /// blossom passes an outer label on to the mate of its base.
+    fn assign_label(&mut self, w: usize, label: u8, p: usize) {
+        let (mut w, mut label, mut p) = (w, label, p);
+        loop {
+            let b = self.in_blossom[w];
+            self.label[w] = label;
+            self.label[b] = label;
+            self.label_end[w] = p;
+            self.label_end[b] = p;
+            self.best_edge[w] = NONE;
+            self.best_edge[b] = NONE;
+
+            if label == OUTER {
+                let leaves = self.leaves(b);
+                self.queue.extend(leaves);
+                return;
+            }
+            let base_mate = self.mate[self.base[b]];
+            w = self.endpoint[base_mate];
+            label = OUTER;
+            p = base_mate ^ 1;
+        }
+    }
+
+    /// Trace back from v and w to find the base of a new blossom, or `NONE`
+    /// if the paths reach two different roots and so form an augmenting path
+    fn scan_blossom(&mut self, v: usize, w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+        let (mut v, mut w) = (v, w);
+
+        while v != NONE || w != NONE {
+            let mut b = self.in_blossom[v];
+            if self.label[b] & BREADCRUMB != 0 {
+                base = self.base[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = OUTER | BREADCRUMB;
+
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                v = self.endpoint[self.label_end[b]];
+                b = self.in_blossom[v];
+                v = self.endpoint[self.label_end[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+
+        for b in path {
+            self.label[b] = OUTER;
+        }
+        base
+    }
+
+    /// Shrink the odd cycle closed by edge k into a new blossom with the given base
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+
+        let b = self.unused.pop().unwrap();
+        self.base[b] = base;
+        self.parent[b] = NONE;
+        self.parent[bb] = b;
+
+        let mut path = Vec::new();
+        let mut ends = Vec::new();
+        while bv != bb {
+            self.parent[bv] = b;
+            path.push(bv);
+            ends.push(self.label_end[bv]);
+            v = self.endpoint[self.label_end[bv]];
+            bv = self.in_blossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        ends.reverse();
+        ends.push(2 * k);
+        while bw != bb {
+            self.parent[bw] = b;
+            path.push(bw);
+            ends.push(self.label_end[bw] ^ 1);
+            w = self.endpoint[self.label_end[bw]];
+            bw = self.in_blossom[w];
+        }
+
+        self.label[b] = OUTER;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = W::zero();
+        for leaf in self.leaves_of(&path) {
+            if self.label[self.in_blossom[leaf]] == INNER {
+                // Former T-vertices become S-vertices and need scanning
+                self.queue.push(leaf);
+            }
+            self.in_blossom[leaf] = b;
+        }
+
+        // Least-slack edges from the new blossom to every other S-blossom
+        let mut best_to = vec![NONE; 2 * self.n];
+        for &child in &path {
+            let lists: Vec<Vec<usize>> = match self.best_edges[child].take() {
+                Some(list) => vec![list],
+                None => self.leaves(child)
+                    .into_iter()
+                    .map(|leaf| self.neighbor_ends[leaf].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for k in lists.into_iter().flatten() {
+                // Look from the end inside the new blossom to the other one
+                let (i, j, _) = self.edges[k];
+                let far = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[far];
+                if bj != b
+                    && self.label[bj] == OUTER
+                    && (best_to[bj] == NONE || self.slack(k) < self.slack(best_to[bj]))
+                {
+                    best_to[bj] = k;
+                }
+            }
+            self.best_edge[child] = NONE;
+        }
+
+        let best: Vec<usize> = best_to.into_iter().filter(|&k| k != NONE).collect();
+        self.best_edge[b] = NONE;
+        for &k in &best {
+            if self.best_edge[b] == NONE || self.slack(k) < self.slack(self.best_edge[b]) {
+                self.best_edge[b] = k;
+            }
+        }
+        self.best_edges[b] = Some(best);
+        self.children[b] = path;
+        self.child_ends[b] = ends;
+    }
+
+    fn leaves_of(&self, blossoms: &[usize]) -> Vec<usize> {
+        blossoms.iter().flat_map(|&b| self.leaves(b)).collect()
+    }
+
+    /// Undo a blossom, relabelling its children if it is expanded mid-stage
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        for s in self.children[b].clone() {
+            self.parent[s] = NONE;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == W::zero() {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for leaf in self.leaves(s) {
+                    self.in_blossom[leaf] = s;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == INNER {
+            let (children, ends) = (self.children[b].clone(), self.child_ends[b].clone());
+            let len = children.len() as isize;
+            let child = |j: isize| children[j.rem_euclid(len) as usize];
+            let end = |j: isize| ends[j.rem_euclid(len) as usize];
+
+            // Walk from the child the blossom was entered through back to its base
+            let entry = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = children.iter().position(|&c| c == entry).unwrap() as isize;
+            let (step, trick): (isize, usize) = if j & 1 == 1 {
+                j -= len;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+
+            let mut p = self.label_end[b];
+            while j != 0 {
+                // Relabel the T-sub-blossom
+                let e = end(j - trick as isize);
+                self.label[self.endpoint[p ^ 1]] = FREE;
+                self.label[self.endpoint[e ^ trick ^ 1]] = FREE;
+                self.assign_label(self.endpoint[p ^ 1], INNER, p);
+
+                // Step to the next S-sub-blossom, then the next T-sub-blossom
+                self.allowed[e / 2] = true;
+                j += step;
+                p = end(j - trick as isize) ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+
+            // The base T-sub-blossom keeps its mate, so no assign_label
+            let bv = child(j);
+            self.label[self.endpoint[p ^ 1]] = INNER;
+            self.label[bv] = INNER;
+            self.label_end[self.endpoint[p ^ 1]] = p;
+            self.label_end[bv] = p;
+            self.best_edge[bv] = NONE;
+
+            // Children past the base become reachable only if an outside S-vertex touches them
+            j += step;
+            while child(j) != entry {
+                let bv = child(j);
+                j += step;
+                if self.label[bv] == OUTER {
+                    continue;
+                }
+                if let Some(v) = self.leaves(bv).into_iter().find(|&v| self.label[v] != FREE) {
+                    self.label[v] = FREE;
+                    self.label[self.endpoint[self.mate[self.base[bv]]]] = FREE;
+                    self.assign_label(v, INNER, self.label_end[v]);
+                }
+            }
+        }
+
+        self.label[b] = FREE;
+        self.label_end[b] = NONE;
+        self.children[b].clear();
+        self.child_ends[b].clear();
+        self.base[b] = NONE;
+        self.best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused.push(b);
+    }
+
+    /// Flip matched and unmatched edges along the even path from v to the base of b
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.parent[t] != b {
+            t = self.parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+
+        let len = self.children[b].len() as isize;
+        let i = self.children[b].iter().position(|&c| c == t).unwrap();
+        let mut j = i as isize;
+        let (step, trick): (isize, usize) = if j & 1 == 1 {
+            j -= len;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+
+        while j != 0 {
+            j += step;
+            let t = self.children[b][j.rem_euclid(len) as usize];
+            let p = self.child_ends[b][(j - trick as isize).rem_euclid(len) as usize] ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += step;
+            let t = self.children[b][j.rem_euclid(len) as usize];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+
+        // Rotate so the new base comes first
+        self.children[b].rotate_left(i);
+        self.child_ends[b].rotate_left(i);
+        self.base[b] = self.base[self.children[b][0]];
+    }
+
+    /// Augment the matching along the path through edge k between two S-vertices
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+                let t = self.endpoint[self.label_end[bs]];
+                let bt = self.in_blossom[t];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    /// Run stages until no augmenting path is left, returning each vertex's mate
+    fn solve(mut self) -> Option<Vec<usize>> {
+        let n = self.n;
+        if !self.duals_fit() {
+            return None;
+        }
+
+        for _ in 0..n {
+            self.label.fill(FREE);
+            self.best_edge.fill(NONE);
+            for b in n..2 * n {
+                self.best_edges[b] = None;
+            }
+            self.allowed.fill(false);
+            self.queue.clear();
+
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == FREE {
+                    self.assign_label(v, OUTER, NONE);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                while !augmented && let Some(v) = self.queue.pop() {
+                    for p in self.neighbor_ends[v].clone() {
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+
+                        let mut slack = W::zero();
+                        if !self.allowed[k] {
+                            slack = self.slack(k);
+                            if slack <= W::zero() {
+                                self.allowed[k] = true;
+                            }
+                        }
+
+                        if self.allowed[k] {
+                            match self.label[self.in_blossom[w]] {
+                                FREE => self.assign_label(w, INNER, p ^ 1),
+                                OUTER => {
+                                    let base = self.scan_blossom(v, w);
+                                    if base != NONE {
+                                        self.add_blossom(base, k);
+                                    } else {
+                                        self.augment_matching(k);
+                                        augmented = true;
+                                        break;
+                                    }
+                                }
+                                _ if self.label[w] == FREE => {
+                                    self.label[w] = INNER;
+                                    self.label_end[w] = p ^ 1;
+                                }
+                                _ => {}
+                            }
+                        } else if self.label[self.in_blossom[w]] == OUTER {
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                                self.best_edge[b] = k;
+                            }
+                        } else if self.label[w] == FREE
+                            && (self.best_edge[w] == NONE || slack < self.slack(self.best_edge[w]))
+                        {
+                            self.best_edge[w] = k;
+                        }
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // No tight edge left to grow along: adjust the duals by the largest safe delta
+                let mut delta: Option<(W, u8, usize)> = None;
+                let mut consider = |d: W, kind: u8, target: usize| {
+                    if delta.is_none_or(|(best, _, _)| d < best) {
+                        delta = Some((d, kind, target));
+                    }
+                };
+
+                for v in 0..n {
+                    if self.label[self.in_blossom[v]] == FREE && self.best_edge[v] != NONE {
+                        consider(self.slack(self.best_edge[v]), 2, self.best_edge[v]);
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.parent[b] == NONE && self.label[b] == OUTER && self.best_edge[b] != NONE {
+                        consider(self.slack(self.best_edge[b]) / self.two, 3, self.best_edge[b]);
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b] != NONE && self.parent[b] == NONE && self.label[b] == INNER {
+                        consider(self.dual[b], 4, b);
+                    }
+                }
+
+                let (delta, kind, target) = delta.unwrap_or_else(|| {
+                    // Maximum cardinality reached: finish with the smallest vertex dual
+                    let smallest = self.dual[..n].iter().copied()
+                        .reduce(|a, b| if b < a { b } else { a })
+                        .unwrap_or(W::zero());
+                    let smallest = if smallest < W::zero() { W::zero() } else { smallest };
+                    (smallest, 1, NONE)
+                });
+
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        OUTER => self.dual[v] = self.dual[v] - delta,
+                        INNER => self.dual[v] = self.dual[v].checked_add(delta)?,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.base[b] != NONE && self.parent[b] == NONE {
+                        match self.label[b] {
+                            OUTER => self.dual[b] = self.dual[b].checked_add(delta)?,
+                            INNER => self.dual[b] = self.dual[b] - delta,
+                            _ => {}
+                        }
+                    }
+                }
+                if !self.duals_fit() {
+                    return None;
+                }
+
+                match kind {
+                    1 => break,
+                    2 => {
+                        self.allowed[target] = true;
+                        let (mut i, j, _) = self.edges[target];
+                        if self.label[self.in_blossom[i]] == FREE {
+                            i = j;
+                        }
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowed[target] = true;
+                        let (i, _, _) = self.edges[target];
+                        self.queue.push(i);
+                    }
+                    _ => self.expand_blossom(target, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            // Blossoms whose dual reached zero may dissolve between stages
+            for b in n..2 * n {
+                if self.parent[b] == NONE
+                    && self.base[b] != NONE
+                    && self.label[b] == OUTER
+                    && self.dual[b] == W::zero()
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+
+        Some(self.mate.iter().map(|&p| if p == NONE { NONE } else { self.endpoint[p] }).collect())
+    }
+}
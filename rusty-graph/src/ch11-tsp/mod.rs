@@ -0,0 +1,12 @@
+pub mod tour;
+pub mod held_karp;
+pub mod nearest_neighbor;
+pub mod local_search;
+pub mod matching;
+pub mod christofides;
+
+pub use christofides::christofides;
+pub use held_karp::{HELD_KARP_LIMIT, held_karp};
+pub use local_search::{or_opt, two_opt};
+pub use nearest_neighbor::nearest_neighbor;
+pub use tour::{Tour, TspError};
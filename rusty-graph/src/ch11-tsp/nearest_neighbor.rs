@@ -0,0 +1,36 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, NumericWeight};
+
+use super::tour::{Distances, Tour, TspError};
+
+/// Nearest-neighbour construction, O(V^2): from `start`, always travel to the
+/// closest unvisited vertex, breaking ties by lowest vertex id.
+///
+/// Fast but can be far from optimal, so it is mostly a starting point for
+/// `two_opt` and `or_opt`.
+pub fn nearest_neighbor<V, E>(graph: &Graph<V, E>, start: &V) -> Result<Tour<V, E>, TspError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let start = graph.vertex_id(start).ok_or_else(|| TspError::UnknownVertex(start.clone()))?;
+    let distances = Distances::new(graph)?;
+    let n = distances.len();
+
+    let mut visited = vec![false; n];
+    visited[start] = true;
+    let mut order = vec![start];
+    let mut current = start;
+    while order.len() < n {
+        current = (0..n)
+            .filter(|&v| !visited[v])
+            .reduce(|a, b| if distances.get(current, b) < distances.get(current, a) { b } else { a })
+            .unwrap();
+        visited[current] = true;
+        order.push(current);
+    }
+
+    Tour::from_ids(graph, &distances, order)
+}
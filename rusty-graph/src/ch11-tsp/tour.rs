@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::representations::{Graph, GraphView, NumericWeight, VertexId};
+
+/// A Hamiltonian cycle: every vertex once, with the last one leading back to the first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tour<V, E> {
+    pub order: Vec<V>,
+    /// Total weight including the closing edge
+    pub cost: E,
+}
+
+impl<V, E> Tour<V, E>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    pub(crate) fn from_ids(
+        graph: &Graph<V, E>,
+        distances: &Distances<E>,
+        order: Vec<VertexId>,
+    ) -> Result<Self, TspError<V>> {
+        Ok(Self {
+            cost: distances.cost(&order).ok_or(TspError::Overflow)?,
+            order: order.into_iter().map(|id| graph.vertex(id).unwrap().clone()).collect(),
+        })
+    }
+}
+
+/// Why no tour could be built
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TspError<V> {
+    /// The graph is not complete: there is no edge from the first vertex to the second
+    MissingEdge(V, V),
+    /// Too many vertices for an exponential algorithm
+    TooLarge { vertices: usize, limit: usize },
+    /// The tour's cost, or for `christofides` the sums its matching works
+    /// with, do not fit the weight type. From `held_karp` every tour overflows.
+    Overflow,
+    /// A tour given for improvement does not visit every vertex exactly once
+    NotATour,
+    /// The start vertex is not in the graph
+    UnknownVertex(V),
+}
+
+impl<V: Debug> fmt::Display for TspError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEdge(u, v) => write!(f, "graph is not complete: no edge {:?} -> {:?}", u, v),
+            Self::TooLarge { vertices, limit } => {
+                write!(f, "{} vertices exceed the limit of {}", vertices, limit)
+            }
+            Self::Overflow => write!(f, "costs overflow the weight type"),
+            Self::NotATour => write!(f, "tour does not visit every vertex exactly once"),
+            Self::UnknownVertex(v) => write!(f, "vertex {:?} is not in the graph", v),
+        }
+    }
+}
+
+impl<V: Debug> Error for TspError<V> {}
+
+/// Dense distance matrix of a complete graph, the lightest of any parallel edges
+pub(crate) struct Distances<E> {
+    pub(crate) matrix: Vec<Vec<E>>,
+}
+
+impl<E: NumericWeight> Distances<E> {
+    pub(crate) fn new<V>(graph: &Graph<V, E>) -> Result<Self, TspError<V>>
+    where
+        V: Eq + Hash + Clone + Debug,
+    {
+        let n = graph.vertex_count();
+        let mut matrix: Vec<Vec<Option<E>>> = vec![vec![None; n]; n];
+        for (u, row) in matrix.iter_mut().enumerate() {
+            row[u] = Some(E::zero());
+            for (v, w) in graph.adjacent(u) {
+                if u != v && row[v].is_none_or(|best| w < best) {
+                    row[v] = Some(w);
+                }
+            }
+        }
+
+        let vertex = |id: VertexId| graph.vertex(id).unwrap().clone();
+        let matrix = matrix.into_iter()
+            .enumerate()
+            .map(|(u, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(v, w)| w.ok_or_else(|| TspError::MissingEdge(vertex(u), vertex(v))))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { matrix })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.matrix.len()
+    }
+
+    pub(crate) fn get(&self, u: VertexId, v: VertexId) -> E {
+        self.matrix[u][v]
+    }
+
+    /// Cost of a closed tour through the given order, `None` if it overflows
+    pub(crate) fn cost(&self, order: &[VertexId]) -> Option<E> {
+        order.iter()
+            .zip(order.iter().cycle().skip(1))
+            .try_fold(E::zero(), |total, (&u, &v)| total.checked_add(self.get(u, v)))
+    }
+
+    /// Whether the edges in `new` weigh less in total than those in `old`,
+    /// counting a total that overflows as larger than any other
+    pub(crate) fn shorter(&self, new: &[(VertexId, VertexId)], old: &[(VertexId, VertexId)]) -> bool {
+        let total = |edges: &[(VertexId, VertexId)]| {
+            edges.iter().try_fold(E::zero(), |total, &(u, v)| total.checked_add(self.get(u, v)))
+        };
+        total(new).is_some_and(|new| total(old).is_none_or(|old| new < old))
+    }
+}
+
+/// Translate a tour back to vertex ids, checking it is a permutation of the graph
+pub(crate) fn tour_ids<V, E>(graph: &Graph<V, E>, tour: &Tour<V, E>) -> Result<Vec<VertexId>, TspError<V>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    let n = graph.vertex_count();
+    let mut seen = vec![false; n];
+    let ids: Vec<VertexId> = tour.order.iter()
+        .map(|v| graph.vertex_id(v).ok_or(TspError::NotATour))
+        .collect::<Result<_, _>>()?;
+
+    if ids.len() != n || ids.iter().any(|&id| std::mem::replace(&mut seen[id], true)) {
+        return Err(TspError::NotATour);
+    }
+    Ok(ids)
+}
@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::representations::{CsrGraph, NumericWeight};
+use crate::shortest_path::dijkstra::dijkstra_ids;
+
+/// Distances between all pairs by Dijkstra from every source, O(V E log V);
+/// `distances[s][t]` is `None` when t is unreachable from s. Weights must
+/// not be negative. Sequential twin of `par_all_pairs_distances`.
+pub fn all_pairs_distances<V, E>(graph: &CsrGraph<V, E>) -> Vec<Vec<Option<E>>>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: NumericWeight,
+{
+    (0..graph.vertex_count()).map(|source| dijkstra_ids(graph, source).0).collect()
+}
+
+/// All-pairs distances with one independent Dijkstra per source run in
+/// parallel. Identical to `all_pairs_distances`.
+#[cfg(feature = "parallel")]
+pub fn par_all_pairs_distances<V, E>(graph: &CsrGraph<V, E>) -> Vec<Vec<Option<E>>>
+where
+    V: Eq + Hash + Clone + Debug + Sync,
+    E: NumericWeight + Send + Sync,
+{
+    (0..graph.vertex_count()).into_par_iter().map(|source| dijkstra_ids(graph, source).0).collect()
+}
@@ -0,0 +1,38 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::representations::{CsrGraph, VertexId, Weight};
+
+/// Level-synchronous parallel BFS: the whole frontier is expanded at once,
+/// claiming each newly reached vertex with a compare-and-swap on its level.
+///
+/// Returns the same distances as `traversal::bfs_distances`: number of edges
+/// on a shortest path from `start`, `None` for unreachable vertices.
+pub fn par_bfs_distances<V, E>(graph: &CsrGraph<V, E>, start: VertexId) -> Vec<Option<usize>>
+where
+    V: Eq + Hash + Clone + Debug + Sync,
+    E: Weight + Sync,
+{
+    const UNSEEN: usize = usize::MAX;
+    let distance: Vec<AtomicUsize> = (0..graph.vertex_count()).map(|_| AtomicUsize::new(UNSEEN)).collect();
+    distance[start].store(0, Ordering::Relaxed);
+
+    let mut frontier = vec![start];
+    let mut level = 0;
+    while !frontier.is_empty() {
+        level += 1;
+        frontier = frontier.par_iter()
+            .flat_map_iter(|&u| graph.neighbors(u).map(|(v, _)| v))
+            .filter(|&v| {
+                distance[v].compare_exchange(UNSEEN, level, Ordering::Relaxed, Ordering::Relaxed).is_ok()
+            })
+            .collect();
+    }
+
+    distance.into_iter()
+        .map(|d| Some(d.into_inner()).filter(|&d| d != UNSEEN))
+        .collect()
+}
@@ -0,0 +1,70 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::connectivity::DisjointSet;
+use crate::representations::{CsrGraph, GraphView, VertexId, Weight};
+
+/// Label every vertex with the lowest vertex id of its connected component,
+/// ignoring edge direction. Sequential twin of `par_component_labels`.
+pub fn component_labels<V, E>(graph: &CsrGraph<V, E>) -> Vec<VertexId>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    let n = graph.vertex_count();
+    let mut components = DisjointSet::new(n);
+    for u in 0..n {
+        for (v, _) in graph.adjacent(u) {
+            components.union(u, v);
+        }
+    }
+
+    // Vertices are visited in id order, so the first one seen per root is the lowest
+    let mut lowest = vec![usize::MAX; n];
+    (0..n)
+        .map(|v| {
+            let root = components.find(v);
+            if lowest[root] == usize::MAX {
+                lowest[root] = v;
+            }
+            lowest[root]
+        })
+        .collect()
+}
+
+/// Parallel connected components by min-label propagation with pointer
+/// jumping, in the spirit of Shiloach-Vishkin. Gives the same labels as
+/// `component_labels`.
+///
+/// Each round every vertex takes the smallest label among itself and its in-
+/// and out-neighbours, then jumps to its label's label. Labels only ever name
+/// vertices of the same component and never grow, so the fixpoint gives each
+/// component its lowest id.
+#[cfg(feature = "parallel")]
+pub fn par_component_labels<V, E>(graph: &CsrGraph<V, E>) -> Vec<VertexId>
+where
+    V: Eq + Hash + Clone + Debug + Sync,
+    E: Weight + Sync,
+{
+    let mut labels: Vec<VertexId> = (0..graph.vertex_count()).collect();
+
+    loop {
+        let hooked: Vec<VertexId> = (0..labels.len()).into_par_iter()
+            .map(|v| {
+                graph.neighbors(v)
+                    .chain(graph.in_neighbors(v))
+                    .map(|(u, _)| labels[u])
+                    .fold(labels[v], VertexId::min)
+            })
+            .collect();
+
+        let jumped: Vec<VertexId> = hooked.par_iter().map(|&label| hooked[label]).collect();
+        if jumped == labels {
+            return labels;
+        }
+        labels = jumped;
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg(feature = "parallel")]
+pub mod bfs;
+pub mod components;
+pub mod pagerank;
+pub mod all_pairs;
+pub mod pregel;
+
+#[cfg(feature = "parallel")]
+pub use all_pairs::par_all_pairs_distances;
+pub use all_pairs::all_pairs_distances;
+#[cfg(feature = "parallel")]
+pub use bfs::par_bfs_distances;
+#[cfg(feature = "parallel")]
+pub use components::par_component_labels;
+pub use components::component_labels;
+#[cfg(feature = "parallel")]
+pub use pagerank::par_pagerank;
+pub use crate::centrality::PageRankOptions;
+pub use pagerank::pagerank;
+pub use pregel::{Aggregator, Context, Pregel, PregelResult, VertexProgram};
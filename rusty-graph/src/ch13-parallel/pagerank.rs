@@ -0,0 +1,42 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "parallel")]
+use crate::centrality::pagerank::{power_iteration, pull};
+use crate::centrality::PageRankOptions;
+use crate::centrality::pagerank::{pagerank_with_teleport, uniform};
+use crate::representations::{CsrGraph, Weight};
+
+/// PageRank by power iteration, pulling rank along in-edges. Sequential twin
+/// of `par_pagerank`.
+///
+/// Every round a vertex gets `(1 - d) / n` plus `d` times the rank flowing in
+/// from its in-neighbours, each of which splits its rank evenly over its out-
+/// edges. Dangling vertices, without out-edges, spread their rank over all
+/// vertices. Parallel edges count separately and undirected edges both ways.
+/// Ranks sum to 1.
+pub fn pagerank<V, E>(graph: &CsrGraph<V, E>, options: PageRankOptions) -> Vec<f64>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    pagerank_with_teleport(graph, options, &uniform(graph.vertex_count()))
+}
+
+/// Parallel PageRank: each round computes the new ranks of all vertices in
+/// parallel. Every rank is summed in the same order as in `pagerank`, so the
+/// results are bit-for-bit identical.
+#[cfg(feature = "parallel")]
+pub fn par_pagerank<V, E>(graph: &CsrGraph<V, E>, options: PageRankOptions) -> Vec<f64>
+where
+    V: Eq + Hash + Clone + Debug + Sync,
+    E: Weight + Sync,
+{
+    let teleport = uniform(graph.vertex_count());
+    power_iteration(graph, options, &teleport, |share, dangling| {
+        (0..graph.vertex_count()).into_par_iter().map(|v| pull(graph, options, &teleport, share, dangling, v)).collect()
+    })
+}
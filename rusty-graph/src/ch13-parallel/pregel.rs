@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::thread;
+
+use crate::representations::{CsrGraph, Graph, VertexId, Weight};
+
+/// A vertex-centric program in the Pregel model: every superstep, each active
+/// vertex reads the messages sent to it, updates its value and sends messages
+/// for the next superstep.
+pub trait VertexProgram<V, E>: Sync
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    /// State kept at every vertex
+    type Value: Send;
+    /// What vertices send each other between supersteps
+    type Message: Send;
+
+    /// Value of a vertex before the first superstep
+    fn initial(&self, vertex: &V) -> Self::Value;
+
+    /// Run at every active vertex with the messages sent to it in the previous superstep
+    fn compute(&self, context: &mut Context<'_, V, E, Self::Value, Self::Message>, messages: Vec<Self::Message>);
+}
+
+/// How an aggregator folds the values contributed during a superstep
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregator {
+    Sum,
+    Min,
+    Max,
+}
+
+impl Aggregator {
+    fn reduce(self, a: f64, b: f64) -> f64 {
+        match self {
+            Self::Sum => a + b,
+            Self::Min => a.min(b),
+            Self::Max => a.max(b),
+        }
+    }
+}
+
+/// A vertex's view of the computation while its `compute` runs
+pub struct Context<'a, V, E, T, M>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    graph: &'a CsrGraph<V, E>,
+    id: VertexId,
+    superstep: usize,
+    value: &'a mut T,
+    halted: &'a mut bool,
+    outbox: &'a mut Vec<(VertexId, M)>,
+    contributions: &'a mut Vec<(usize, f64)>,
+    aggregators: &'a [(&'static str, Aggregator)],
+    aggregated: &'a [Option<f64>],
+}
+
+impl<V, E, T, M> Context<'_, V, E, T, M>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+{
+    /// Get the id of this vertex
+    pub fn id(&self) -> VertexId {
+        self.id
+    }
+
+    /// Get the vertex this computation runs at
+    pub fn vertex(&self) -> &V {
+        self.graph.vertex(self.id).unwrap()
+    }
+
+    /// Get the number of the current superstep, starting at 0
+    pub fn superstep(&self) -> usize {
+        self.superstep
+    }
+
+    /// Get the number of vertices in the graph
+    pub fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+
+    /// Get the value this vertex holds
+    pub fn value(&self) -> &T {
+        self.value
+    }
+
+    /// Get the value this vertex holds for updating
+    pub fn value_mut(&mut self) -> &mut T {
+        self.value
+    }
+
+    /// Iterate over `(target, weight)` of the outgoing edges
+    pub fn edges(&self) -> impl Iterator<Item = (VertexId, &E)> {
+        self.graph.neighbors(self.id)
+    }
+
+    /// Get the number of outgoing edges
+    pub fn out_degree(&self) -> usize {
+        self.graph.out_degree(self.id)
+    }
+
+    /// Send a message that `to` receives in the next superstep
+    pub fn send(&mut self, to: VertexId, message: M) {
+        self.outbox.push((to, message));
+    }
+
+    /// Send the same message along every outgoing edge
+    pub fn send_to_neighbors(&mut self, message: M)
+    where
+        M: Clone,
+    {
+        for (to, _) in self.graph.neighbors(self.id) {
+            self.outbox.push((to, message.clone()));
+        }
+    }
+
+    /// Deactivate this vertex until a message arrives for it
+    pub fn vote_to_halt(&mut self) {
+        *self.halted = true;
+    }
+
+    /// Contribute a value to the named aggregator; the result is visible to
+    /// every vertex in the next superstep.
+    ///
+    /// Panics if no aggregator of that name was registered.
+    pub fn aggregate(&mut self, name: &str, value: f64) {
+        let index = self.aggregators.iter()
+            .position(|&(registered, _)| registered == name)
+            .unwrap_or_else(|| panic!("no aggregator named {:?}", name));
+        self.contributions.push((index, value));
+    }
+
+    /// Get what the named aggregator folded in the previous superstep, `None`
+    /// if nothing was contributed or no such aggregator exists
+    pub fn aggregated(&self, name: &str) -> Option<f64> {
+        let index = self.aggregators.iter().position(|&(registered, _)| registered == name)?;
+        self.aggregated[index]
+    }
+}
+
+/// Final state of a Pregel run
+#[derive(Debug, Clone)]
+pub struct PregelResult<V, T> {
+    /// Value of every vertex
+    pub values: HashMap<V, T>,
+    /// Number of supersteps run
+    pub supersteps: usize,
+    /// Aggregator results of the last superstep that had contributions
+    pub aggregates: HashMap<&'static str, f64>,
+}
+
+type Combiner<'a, M> = Box<dyn Fn(M, M) -> M + Sync + 'a>;
+
+/// Single-process bulk synchronous parallel engine in the style of Pregel
+///
+/// Supersteps run over a frozen snapshot of the graph. A vertex is active
+/// until it votes to halt and is woken again by any incoming message; the run
+/// ends once every vertex is halted with no messages in flight, or after
+/// `max_supersteps`. Vertices are split into contiguous ranges, one per worker
+/// thread, and messages are delivered in sender id order, so the outcome does
+/// not depend on the number of workers.
+pub struct Pregel<'a, V, E, P>
+where
+    V: Eq + Hash + Clone + Debug,
+    E: Weight,
+    P: VertexProgram<V, E>,
+{
+    graph: CsrGraph<V, E>,
+    program: P,
+    combiner: Option<Combiner<'a, P::Message>>,
+    aggregators: Vec<(&'static str, Aggregator)>,
+    workers: usize,
+    max_supersteps: usize,
+}
+
+impl<'a, V, E, P> Pregel<'a, V, E, P>
+where
+    V: Eq + Hash + Clone + Debug + Sync,
+    E: Weight + Sync,
+    P: VertexProgram<V, E>,
+{
+    /// Prepare a run of `program` over a snapshot of `graph` with one worker
+    pub fn new(graph: &Graph<V, E>, program: P) -> Self {
+        Self {
+            graph: graph.freeze(),
+            program,
+            combiner: None,
+            aggregators: Vec::new(),
+            workers: 1,
+            max_supersteps: usize::MAX,
+        }
+    }
+
+    /// Merge messages bound for the same vertex, so `compute` receives at most one.
+    /// Must be associative and commutative, like a sum or minimum.
+    pub fn combiner(mut self, combine: impl Fn(P::Message, P::Message) -> P::Message + Sync + 'a) -> Self {
+        self.combiner = Some(Box::new(combine));
+        self
+    }
+
+    /// Register a global aggregator that vertices contribute to by name
+    pub fn aggregator(mut self, name: &'static str, aggregator: Aggregator) -> Self {
+        self.aggregators.push((name, aggregator));
+        self
+    }
+
+    /// Run each superstep across this many threads
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Stop after at most this many supersteps
+    pub fn max_supersteps(mut self, max_supersteps: usize) -> Self {
+        self.max_supersteps = max_supersteps;
+        self
+    }
+
+    /// Run supersteps until the computation halts
+    pub fn run(&self) -> PregelResult<V, P::Value> {
+        let n = self.graph.vertex_count();
+        let mut values: Vec<P::Value> = self.graph.vertices().iter().map(|v| self.program.initial(v)).collect();
+        let mut halted = vec![false; n];
+        let mut inbox: Vec<Vec<P::Message>> = (0..n).map(|_| Vec::new()).collect();
+        let mut aggregated = vec![None; self.aggregators.len()];
+        let mut last_aggregates = HashMap::new();
+        let mut superstep = 0;
+
+        while superstep < self.max_supersteps
+            && (halted.iter().any(|&h| !h) || inbox.iter().any(|messages| !messages.is_empty()))
+        {
+            let chunk = n.div_ceil(self.workers).max(1);
+            let ranges = values.chunks_mut(chunk)
+                .zip(halted.chunks_mut(chunk))
+                .zip(inbox.chunks_mut(chunk))
+                .enumerate()
+                .map(|(i, ((values, halted), inbox))| (i * chunk, values, halted, inbox));
+
+            let outputs: Vec<WorkerOutput<P::Message>> = if self.workers == 1 {
+                ranges.map(|(first, values, halted, inbox)| {
+                    self.superstep(first, values, halted, inbox, superstep, &aggregated)
+                }).collect()
+            } else {
+                thread::scope(|scope| {
+                    let handles: Vec<_> = ranges
+                        .map(|(first, values, halted, inbox)| {
+                            let aggregated = &aggregated;
+                            scope.spawn(move || self.superstep(first, values, halted, inbox, superstep, aggregated))
+                        })
+                        .collect();
+                    handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+                })
+            };
+
+            // Deliver and fold in worker order, which is sender id order
+            aggregated = vec![None; self.aggregators.len()];
+            for (outbox, contributions) in outputs {
+                for (to, message) in outbox {
+                    let slot = &mut inbox[to];
+                    match (&self.combiner, slot.pop()) {
+                        (Some(combine), Some(previous)) => slot.push(combine(previous, message)),
+                        (_, previous) => slot.extend(previous.into_iter().chain([message])),
+                    }
+                }
+                for (index, value) in contributions {
+                    let aggregator = self.aggregators[index].1;
+                    aggregated[index] = Some(aggregated[index].map_or(value, |a| aggregator.reduce(a, value)));
+                }
+            }
+            if aggregated.iter().any(Option::is_some) {
+                last_aggregates = self.aggregators.iter()
+                    .zip(&aggregated)
+                    .filter_map(|(&(name, _), value)| value.map(|value| (name, value)))
+                    .collect();
+            }
+            superstep += 1;
+        }
+
+        PregelResult {
+            values: self.graph.vertices().iter().cloned().zip(values).collect(),
+            supersteps: superstep,
+            aggregates: last_aggregates,
+        }
+    }
+
+    /// Run one superstep over the vertices `first..first + values.len()`
+    fn superstep(
+        &self,
+        first: VertexId,
+        values: &mut [P::Value],
+        halted: &mut [bool],
+        inbox: &mut [Vec<P::Message>],
+        superstep: usize,
+        aggregated: &[Option<f64>],
+    ) -> WorkerOutput<P::Message> {
+        let mut outbox = Vec::new();
+        let mut contributions = Vec::new();
+
+        for (offset, ((value, halted), messages)) in values.iter_mut().zip(halted).zip(inbox).enumerate() {
+            let messages = std::mem::take(messages);
+            if *halted && messages.is_empty() {
+                continue;
+            }
+            *halted = false;
+
+            let mut context = Context {
+                graph: &self.graph,
+                id: first + offset,
+                superstep,
+                value,
+                halted,
+                outbox: &mut outbox,
+                contributions: &mut contributions,
+                aggregators: &self.aggregators,
+                aggregated,
+            };
+            self.program.compute(&mut context, messages);
+        }
+
+        (outbox, contributions)
+    }
+}
+
+/// Messages sent and aggregator contributions made by one worker
+type WorkerOutput<M> = (Vec<(VertexId, M)>, Vec<(usize, f64)>);
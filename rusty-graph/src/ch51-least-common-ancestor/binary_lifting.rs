@@ -1,3 +1,5 @@
+use super::trees::{BinaryTreeNode, Tree};
+
 /// Binary Lifting LCA structure
 pub struct BinaryLiftingLCA {
     up: Vec<Vec<usize>>,  // up[k][node] = 2^k-th ancestor
@@ -38,7 +40,7 @@ impl BinaryLiftingLCA {
 
         // Jump u up to v's depth
         for k in (0..self.log_max).rev() {
-            if self.depth[u] - (1 << k) >= self.depth[v] {
+            if self.depth[u] >= self.depth[v] + (1 << k) {
                 u = self.up[k][u];
             }
         }
@@ -59,13 +61,14 @@ impl BinaryLiftingLCA {
     }
 }
 
-struct BinaryLifter {
+/// Binary lifting over a linked binary tree whose node values are ids in `0..node_count`
+pub struct BinaryLifter {
     up: Vec<Vec<Option<usize>>>, // up[depth][node]
     depth: Vec<usize>,
 }
 
 impl BinaryLifter {
-    fn new(root: &BinaryTreeNode, node_count: usize) -> Self {
+    pub fn new(root: &BinaryTreeNode<usize>, node_count: usize) -> Self {
         let max_depth = (node_count as f64).log2().ceil() as usize;
         let mut lifter = BinaryLifter {
             up: vec![vec![None; node_count]; max_depth + 1],
@@ -76,7 +79,7 @@ impl BinaryLifter {
         lifter
     }
 
-    fn dfs(&mut self, node: &BinaryTreeNode, parent: Option<usize>) {
+    fn dfs(&mut self, node: &BinaryTreeNode<usize>, parent: Option<usize>) {
         let node_id = node.value;
         self.up[0][node_id] = parent;
         self.depth[node_id] = parent.map_or(0, |p| self.depth[p] + 1);
@@ -88,4 +91,39 @@ impl BinaryLifter {
             self.dfs(&right.borrow(), Some(node_id));
         }
     }
-}
\ No newline at end of file
+
+    /// Fill the higher jump levels from the direct parents
+    fn preprocess(&mut self) {
+        for k in 1..self.up.len() {
+            for node in 0..self.depth.len() {
+                self.up[k][node] = self.up[k - 1][node].and_then(|mid| self.up[k - 1][mid]);
+            }
+        }
+    }
+
+    /// Query LCA in O(log n) time
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        if self.depth[u] < self.depth[v] {
+            std::mem::swap(&mut u, &mut v);
+        }
+
+        for k in (0..self.up.len()).rev() {
+            if self.depth[u] >= self.depth[v] + (1 << k) {
+                u = self.up[k][u].unwrap();
+            }
+        }
+
+        if u == v {
+            return u;
+        }
+
+        for k in (0..self.up.len()).rev() {
+            if self.up[k][u] != self.up[k][v] {
+                u = self.up[k][u].unwrap();
+                v = self.up[k][v].unwrap();
+            }
+        }
+
+        self.up[0][u].unwrap()
+    }
+}
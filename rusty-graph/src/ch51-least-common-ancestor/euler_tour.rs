@@ -1,8 +1,9 @@
+use super::trees::Tree;
+
 /// Euler Tour LCA using RMQ
 pub struct EulerTourLCA {
     first_occurrence: Vec<usize>,
     euler_tour: Vec<usize>,
-    depth: Vec<usize>,
     rmq: SparseTable,
 }
 
@@ -12,9 +13,8 @@ impl EulerTourLCA {
         let n = tree.nodes.len();
         let mut euler_tour = Vec::with_capacity(2 * n);
         let mut first_occurrence = vec![0; n];
-        let mut depth = vec![0; n];
 
-        // Perform Euler Tour DFS
+        // Perform Euler Tour DFS, revisiting a node after each of its children
         let mut stack = vec![(tree.root, false)];
         while let Some((node, visited)) = stack.pop() {
             if visited {
@@ -24,22 +24,20 @@ impl EulerTourLCA {
 
             first_occurrence[node] = euler_tour.len();
             euler_tour.push(node);
-            depth[node] = tree.nodes[node].depth;
 
-            stack.push((node, true));
             for &child in tree.nodes[node].children.iter().rev() {
+                stack.push((node, true));
                 stack.push((child, false));
             }
         }
 
         // Build RMQ on depths
-        let depths: Vec<_> = euler_tour.iter().map(|&n| depth[n]).collect();
+        let depths: Vec<_> = euler_tour.iter().map(|&n| tree.nodes[n].depth).collect();
         let rmq = SparseTable::new(&depths);
 
         Self {
             first_occurrence,
             euler_tour,
-            depth,
             rmq,
         }
     }
@@ -57,8 +55,9 @@ impl EulerTourLCA {
     }
 }
 
-/// Sparse Table for RMQ
+/// Sparse Table for RMQ, answering with the position of the minimum
 struct SparseTable {
+    data: Vec<usize>,
     table: Vec<Vec<usize>>,
     log: Vec<usize>,
 }
@@ -73,26 +72,22 @@ impl SparseTable {
 
         let k = log[n] + 1;
         let mut table = vec![vec![0; n]; k];
-        table[0] = data.to_vec();
+        table[0] = (0..n).collect();
 
         for j in 1..k {
             for i in 0..=n - (1 << j) {
-                table[j][i] = std::cmp::min(
-                    table[j-1][i],
-                    table[j-1][i + (1 << (j-1))],
-                );
+                let (a, b) = (table[j-1][i], table[j-1][i + (1 << (j-1))]);
+                table[j][i] = if data[a] <= data[b] { a } else { b };
             }
         }
 
-        Self { table, log }
+        Self { data: data.to_vec(), table, log }
     }
 
     fn query(&self, l: usize, r: usize) -> usize {
         let len = r - l + 1;
         let k = self.log[len];
-        std::cmp::min(
-            self.table[k][l],
-            self.table[k][r + 1 - (1 << k)],
-        )
+        let (a, b) = (self.table[k][l], self.table[k][r + 1 - (1 << k)]);
+        if self.data[a] <= self.data[b] { a } else { b }
     }
 }
\ No newline at end of file
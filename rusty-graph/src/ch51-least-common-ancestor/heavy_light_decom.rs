@@ -1,10 +1,11 @@
+use super::trees::Tree;
+
 /// Heavy-Light Decomposition for LCA and path queries
 pub struct HLDLCA {
     parent: Vec<usize>,
     depth: Vec<usize>,
     head: Vec<usize>,  // Head of heavy path
     pos: Vec<usize>,   // Position in segment tree
-    chain: Vec<usize>, // Chain ID
 }
 
 impl HLDLCA {
@@ -16,7 +17,6 @@ impl HLDLCA {
             depth: vec![0; n],
             head: vec![0; n],
             pos: vec![0; n],
-            chain: vec![0; n],
         };
 
         // First DFS to compute sizes
@@ -36,14 +36,14 @@ impl HLDLCA {
         }
 
         // Second DFS to build HLD
-        let mut current_chain = 0;
         let mut current_pos = 0;
         let mut stack = vec![(tree.root, tree.root)];
         while let Some((node, head)) = stack.pop() {
+            hld.parent[node] = tree.nodes[node].parent.unwrap_or(node);
+            hld.depth[node] = tree.nodes[node].depth;
             hld.head[node] = head;
             hld.pos[node] = current_pos;
             current_pos += 1;
-            hld.chain[node] = current_chain;
 
             if !tree.nodes[node].children.is_empty() {
                 // Find heavy child
@@ -54,25 +54,30 @@ impl HLDLCA {
                     }
                 }
 
-                // Continue current chain with heavy child
-                stack.push((heavy_child, head));
-
                 // Start new chains for other children
                 for &child in &tree.nodes[node].children {
                     if child != heavy_child {
-                        current_chain += 1;
                         stack.push((child, child));
                     }
                 }
+
+                // Continue current chain with heavy child, popped next so
+                // every chain occupies consecutive positions
+                stack.push((heavy_child, head));
             }
         }
 
         hld
     }
 
+    /// Position of a node in the base array of a segment tree over the chains
+    pub fn position(&self, node: usize) -> usize {
+        self.pos[node]
+    }
+
     /// Query LCA in O(log n) time
     pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
-        while self.chain[u] != self.chain[v] {
+        while self.head[u] != self.head[v] {
             if self.depth[self.head[u]] > self.depth[self.head[v]] {
                 u = self.parent[self.head[u]];
             } else {
@@ -82,4 +87,4 @@ impl HLDLCA {
 
         if self.depth[u] < self.depth[v] { u } else { v }
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,6 @@
 /// Link-Cut Tree for dynamic LCA queries
 pub struct LinkCutTree {
-    parent: Vec<Option<usize>>,
+    parent: Vec<Option<usize>>, // Splay parent, or path-parent for the root of an auxiliary tree
     left: Vec<Option<usize>>,
     right: Vec<Option<usize>>,
     flip: Vec<bool>,
@@ -16,52 +16,71 @@ impl LinkCutTree {
         }
     }
 
+    /// Whether x is the root of its auxiliary tree
+    fn is_root(&self, x: usize) -> bool {
+        match self.parent[x] {
+            Some(p) => self.left[p] != Some(x) && self.right[p] != Some(x),
+            None => true,
+        }
+    }
+
     /// Makes the node the root of its auxiliary tree
     fn splay(&mut self, x: usize) {
-        self.push(x);
-        while let Some(p) = self.parent[x] {
-            let g = self.parent[p];
-            if g.is_some() {
-                self.push(g.unwrap());
-            }
-            self.push(p);
-            self.push(x);
-
-            let x_is_left = self.left[p] == Some(x);
-            let p_is_left = g.map_or(false, |g| self.left[g] == Some(p));
+        // Push pending flips down from the auxiliary root to x
+        let mut path = vec![x];
+        let mut y = x;
+        while !self.is_root(y) {
+            y = self.parent[y].unwrap();
+            path.push(y);
+        }
+        for &node in path.iter().rev() {
+            self.push(node);
+        }
 
-            if p_is_left == x_is_left {
-                self.rotate(p, x_is_left);
-            } else {
-                self.rotate(x, x_is_left);
+        while !self.is_root(x) {
+            let p = self.parent[x].unwrap();
+            if !self.is_root(p) {
+                let g = self.parent[p].unwrap();
+                let x_is_left = self.left[p] == Some(x);
+                let p_is_left = self.left[g] == Some(p);
+
+                if p_is_left == x_is_left {
+                    self.rotate(p);
+                } else {
+                    self.rotate(x);
+                }
             }
-            self.rotate(x, p_is_left);
+            self.rotate(x);
         }
     }
 
     /// Rotates x with its parent
-    fn rotate(&mut self, x: usize, left: bool) {
+    fn rotate(&mut self, x: usize) {
         let p = self.parent[x].unwrap();
         let g = self.parent[p];
+        let p_is_root = self.is_root(p);
 
-        if left {
+        if self.left[p] == Some(x) {
             let b = self.right[x];
             self.right[x] = Some(p);
             self.left[p] = b;
+            if let Some(b) = b {
+                self.parent[b] = Some(p);
+            }
         } else {
             let b = self.left[x];
             self.left[x] = Some(p);
             self.right[p] = b;
+            if let Some(b) = b {
+                self.parent[b] = Some(p);
+            }
         }
 
         self.parent[p] = Some(x);
         self.parent[x] = g;
 
-        if let Some(b) = if left { self.left[p] } else { self.right[p] } {
-            self.parent[b] = Some(p);
-        }
-
-        if let Some(g) = g {
+        // A path-parent pointer is inherited without becoming a child link
+        if let Some(g) = g.filter(|_| !p_is_root) {
             if self.left[g] == Some(p) {
                 self.left[g] = Some(x);
             } else {
@@ -87,7 +106,7 @@ impl LinkCutTree {
     /// Makes x the root of its represented tree
     pub fn make_root(&mut self, x: usize) {
         self.access(x);
-        self.flip[x] = true;
+        self.flip[x] ^= true;
     }
 
     /// Connects x to the root of y's tree
@@ -105,20 +124,34 @@ impl LinkCutTree {
         }
     }
 
-    /// Brings x to the root of its auxiliary tree
-    pub fn access(&mut self, mut x: usize) {
+    /// Brings x to the root of its auxiliary tree, with the root-to-x path as
+    /// its preferred path. Returns the last node where a path-parent jump landed.
+    pub fn access(&mut self, x: usize) -> usize {
         let mut last = None;
-        let mut y = x;
+        let mut y = Some(x);
 
-        while let Some(p) = self.parent[y] {
-            self.splay(p);
-            self.right[p] = last;
-            last = Some(p);
-            y = p;
+        while let Some(v) = y {
+            self.splay(v);
+            self.right[v] = last;
+            last = Some(v);
+            y = self.parent[v];
         }
 
         self.splay(x);
-        self.right[x] = last;
+        last.unwrap()
+    }
+
+    /// Finds the root of the represented tree containing x
+    pub fn find_root(&mut self, x: usize) -> usize {
+        self.access(x);
+        let mut root = x;
+        self.push(root);
+        while let Some(l) = self.left[root] {
+            root = l;
+            self.push(root);
+        }
+        self.splay(root);
+        root
     }
 
     /// Finds LCA of x and y
@@ -126,15 +159,11 @@ impl LinkCutTree {
         if x == y {
             return Some(x);
         }
+        if self.find_root(x) != self.find_root(y) {
+            return None;
+        }
 
         self.access(x);
-        self.access(y);
-
-        self.splay(x);
-        if self.parent[x].is_some() {
-            Some(x)
-        } else {
-            None
-        }
+        Some(self.access(y))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,9 @@
+pub mod trees;
+pub mod naive_parent_jumping;
+pub mod binary_lifting;
+pub mod euler_tour;
+pub mod heavy_light_decom;
+pub mod tarjan_lca;
+pub mod link_cut_tree;
+pub mod online_single_query;
+pub mod online_single_query_ext;
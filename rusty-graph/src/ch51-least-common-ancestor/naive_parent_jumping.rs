@@ -1,3 +1,5 @@
+use super::trees::Tree;
+
 impl Tree {
     /// Naive LCA implementation using parent jumping
     /// Time: O(h) per query where h is tree height
@@ -18,4 +20,4 @@ impl Tree {
 
         u
     }
-}
\ No newline at end of file
+}
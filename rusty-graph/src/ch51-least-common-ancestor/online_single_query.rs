@@ -1,3 +1,8 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::trees::TreeNode;
+
 /// 1. Simple BST LCA
 ///
 ///
@@ -51,18 +56,18 @@ pub fn lca_bst_recursive(
     let q_val = q.as_ref().unwrap().borrow().val;
 
     if p_val < root_val && q_val < root_val {
-        lowest_common_ancestor_recursive(root.unwrap().borrow().left.clone(), p, q)
+        lca_bst_recursive(root.unwrap().borrow().left.clone(), p, q)
     } else if p_val > root_val && q_val > root_val {
-        lowest_common_ancestor_recursive(root.unwrap().borrow().right.clone(), p, q)
+        lca_bst_recursive(root.unwrap().borrow().right.clone(), p, q)
     } else {
         root
     }
 }
 
-/// 2. General Binary Tree LCA
-///
-/// The binary tree is a single-rooted tree and maximum two children are allowed for each
-/// tree node. The tree node may not have orders except for its binary topological order
+// 2. General Binary Tree LCA
+//
+// The binary tree is a single-rooted tree and maximum two children are allowed for each
+// tree node. The tree node may not have orders except for its binary topological order
 
 
 /// The default implementation for single-query online Binary Tree LCA with recursive DFS
@@ -133,8 +138,9 @@ pub fn lca_iterative(
     let mut ancestors = HashSet::new();
     let mut current = p.unwrap();
     loop {
-        ancestors.insert(current.borrow().val);
-        match parent_map.get(&current.borrow().val) {
+        let val = current.borrow().val;
+        ancestors.insert(val);
+        match parent_map.get(&val) {
             Some(parent) => current = parent.clone(),
             None => break,
         }
@@ -143,10 +149,11 @@ pub fn lca_iterative(
     // Find the first common ancestor of q in p's ancestors.
     let mut current_q = q.unwrap();
     loop {
-        if ancestors.contains(&current_q.borrow().val) {
+        let val = current_q.borrow().val;
+        if ancestors.contains(&val) {
             return Some(current_q);
         }
-        current_q = parent_map.get(&current_q.borrow().val).unwrap().clone();
+        current_q = parent_map[&val].clone();
     }
 }
 
@@ -158,8 +165,8 @@ pub fn lca_binary(root: &TreeNode, p: i32, q: i32) -> Option<i32> {
     }
 
     //depth-first search on each of children if exists
-    let left = root.left.as_ref().and_then(|n| lca_binary(n, p, q));
-    let right = root.right.as_ref().and_then(|n| lca_binary(n, p, q));
+    let left = root.left.as_ref().and_then(|n| lca_binary(&n.borrow(), p, q));
+    let right = root.right.as_ref().and_then(|n| lca_binary(&n.borrow(), p, q));
 
     //fist found parent, i.e. the deepest from root, or the shallowest from
     //the two children, is the LCA
@@ -172,7 +179,7 @@ pub fn lca_binary(root: &TreeNode, p: i32, q: i32) -> Option<i32> {
 }
 
 /// A variation of the above implementation with node reference
-pub fn lca_binary(
+pub fn lca_binary_rc(
     root: Option<Rc<RefCell<TreeNode>>>,
     p: Option<Rc<RefCell<TreeNode>>>,
     q: Option<Rc<RefCell<TreeNode>>>,
@@ -180,8 +187,8 @@ pub fn lca_binary(
     if root.is_none() || root == p || root == q {
         return root;
     }
-    let left = lca_binary(root.as_ref().unwrap().borrow().left.clone(), p.clone(), q.clone());
-    let right = lca_binary(root.as_ref().unwrap().borrow().right.clone(), p, q);
+    let left = lca_binary_rc(root.as_ref().unwrap().borrow().left.clone(), p.clone(), q.clone());
+    let right = lca_binary_rc(root.as_ref().unwrap().borrow().right.clone(), p, q);
 
     match (left.is_some(), right.is_some()) {
         (true, true) => root,
@@ -228,4 +235,3 @@ pub fn lca_count(
     dfs(&root, p.unwrap().borrow().val, q.unwrap().borrow().val, &mut lca);
     lca
 }
-
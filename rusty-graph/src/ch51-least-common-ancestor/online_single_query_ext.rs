@@ -1,7 +1,11 @@
-/// 2. Online Single Query Extension
-///
-/// This is the second part of single-query LCA algorithm compilation, with extending
-/// to arbitrary tree, bidirectional trees, and k-node LCA finding algorithm.
+//! 2. Online Single Query Extension
+//!
+//! This is the second part of single-query LCA algorithm compilation, with extending
+//! to arbitrary tree, bidirectional trees, and k-node LCA finding algorithm.
+
+use std::collections::{HashMap, HashSet};
+
+use super::trees::Employee;
 
 ///
 /// Algorithm: Recursive DFS (No Preprocessing)
@@ -61,7 +65,7 @@ pub fn find_lca(root: &Employee, p_id: i32, q_id: i32) -> Option<i32> {
 ///
 /// Time: O(N) per query. Space: O(N) (path storage).
 ///
-fn lca_arbitrary(root: &Employee, p: i32, q: i32) -> Option<i32> {
+pub fn lca_arbitrary(root: &Employee, p: i32, q: i32) -> Option<i32> {
     fn get_path(node: &Employee, target: i32, path: &mut Vec<i32>) -> bool {
         path.push(node.id);
         if node.id == target {
@@ -101,9 +105,6 @@ fn lca_arbitrary(root: &Employee, p: i32, q: i32) -> Option<i32> {
 /// Time: O(N) per query.
 ///
 /// Space: O(N) (visited nodes).
-
-use std::collections::{HashMap, HashSet, VecDeque};
-
 pub fn lca_bidirectional(root: &Employee, p: i32, q: i32) -> Option<i32> {
     let mut parent_map = HashMap::new();
     let mut stack = vec![root];
@@ -116,15 +117,15 @@ pub fn lca_bidirectional(root: &Employee, p: i32, q: i32) -> Option<i32> {
 
     let mut ancestors_p = HashSet::new();
     let mut current = p;
-    while let Some(&parent) = parent_map.get(current) {
+    while let Some(&parent) = parent_map.get(&current) {
         ancestors_p.insert(current);
         current = parent;
     }
     ancestors_p.insert(current); // Include root
 
     let mut current_q = q;
-    while !ancestors_p.contains(current_q) {
-        current_q = parent_map[current_q];
+    while !ancestors_p.contains(&current_q) {
+        current_q = parent_map[&current_q];
     }
     Some(current_q)
 }
@@ -157,30 +158,29 @@ pub fn lca_k_nodes(root: &Employee, nodes: &[i32]) -> Option<i32> {
     for i in 0.. {
         let mut current = None;
         for path in &paths {
-            if i >= path.len() || (current.is_some() && path[i] != current.unwrap()) {
+            if i >= path.len() || current.is_some_and(|c| path[i] != c) {
                 return Some(lca);
             }
             current = Some(path[i]);
         }
         lca = current.unwrap();
     }
-    Some(lca)
+    unreachable!()
 }
 
 
-///
-pub fn lca_with_count(root: &Rc<RefCell<Employee>>, p: i32, q: i32) -> Option<i32> {
+/// Single-query LCA reporting which of the two targets each subtree contains
+pub fn lca_with_count(root: &Employee, p: i32, q: i32) -> Option<i32> {
     fn dfs(
-        node: &Rc<RefCell<Employee>>,
+        node: &Employee,
         p: i32,
         q: i32,
         lca: &mut Option<i32>,
     ) -> (bool, bool) {
-        let node_ref = node.borrow();
-        let mut found_p = node_ref.id == p;
-        let mut found_q = node_ref.id == q;
+        let mut found_p = node.id == p;
+        let mut found_q = node.id == q;
 
-        for child in &node_ref.children {
+        for child in &node.children {
             let (child_found_p, child_found_q) = dfs(child, p, q, lca);
             found_p = found_p || child_found_p;
             found_q = found_q || child_found_q;
@@ -188,7 +188,7 @@ pub fn lca_with_count(root: &Rc<RefCell<Employee>>, p: i32, q: i32) -> Option<i3
 
         // If both p and q are found for the first time, set LCA.
         if found_p && found_q && lca.is_none() {
-            *lca = Some(node_ref.id);
+            *lca = Some(node.id);
         }
 
         (found_p, found_q)
@@ -199,21 +199,21 @@ pub fn lca_with_count(root: &Rc<RefCell<Employee>>, p: i32, q: i32) -> Option<i3
     lca
 }
 
-fn find_lca_by_count(root: &Rc<RefCell<Employee>>, p: i32, q: i32) -> Option<Rc<RefCell<Employee>>> {
-    fn dfs(
-        node: &Rc<RefCell<Employee>>,
+/// Single-query LCA counting the targets found in each subtree, returning the node itself
+pub fn find_lca_by_count(root: &Employee, p: i32, q: i32) -> Option<&Employee> {
+    fn dfs<'a>(
+        node: &'a Employee,
         p: i32,
         q: i32,
-        lca: &mut Option<Rc<RefCell<Employee>>>,
+        lca: &mut Option<&'a Employee>,
     ) -> i32 {
-        let node_ref = node.borrow();
-        let mut count = if node_ref.id == p || node_ref.id == q { 1 } else { 0 };
+        let mut count = if node.id == p || node.id == q { 1 } else { 0 };
 
-        for child in &node_ref.children {
+        for child in &node.children {
             let child_count = dfs(child, p, q, lca);
             // LCA condition: Current node is between two targets.
             if child_count == 1 && count == 1 && lca.is_none() {
-                *lca = Some(node.clone());
+                *lca = Some(node);
             }
             count += child_count;
         }
@@ -226,46 +226,30 @@ fn find_lca_by_count(root: &Rc<RefCell<Employee>>, p: i32, q: i32) -> Option<Rc<
     lca
 }
 
-pub fn lca_k_nodes_with_count(root: &Rc<RefCell<Employee>>, nodes: &[i32],
-    node: &Rc<RefCell<Employee>>,
-    targets: &HashSet<i32>,
-    lca: &mut Option<i32>,
-) -> usize {
-    let node_ref = node.borrow();
-    let mut count = if targets.contains(&node_ref.id) { 1 } else { 0 };
-
-    for child in &node_ref.children {
-        count += lca_k_nodes_with_count(child, targets, lca);
-    }
-
-    if count == targets.len() && lca.is_none() {
-        *lca = Some(node_ref.id);
-    }
-    count
-}
+/// K-node LCA: the deepest node whose subtree contains every target
+pub fn lca_k_nodes_with_count(root: &Employee, nodes: &[i32]) -> Option<i32> {
+    fn dfs(
+        node: &Employee,
+        targets: &HashSet<i32>,
+        lca: &mut Option<i32>,
+    ) -> usize {
+        let mut count = if targets.contains(&node.id) { 1 } else { 0 };
 
-fn dfs_k_nodes(
-    node: &Rc<RefCell<Employee>>,
-    targets: &HashSet<i32>,
-    lca: &mut Option<Rc<RefCell<Employee>>>,
-) -> usize {
-    let node_ref = node.borrow();
-    let mut count = if targets.contains(&node_ref.id) { 1 } else { 0 };
+        for child in &node.children {
+            count += dfs(child, targets, lca);
+        }
 
-    for child in &node_ref.children {
-        let child_count = dfs_k_nodes(child, targets, lca);
-        if child_count == 1 && count == targets.len() - 1 && lca.is_none() {
-            *lca = Some(node.clone());
+        if count == targets.len() && lca.is_none() {
+            *lca = Some(node.id);
         }
-        count += child_count;
+        count
     }
 
-    count
+    let targets: HashSet<i32> = nodes.iter().copied().collect();
+    let mut lca = None;
+    dfs(root, &targets, &mut lca);
+    lca
 }
 
-
-
 #[cfg(test)]
 mod tests {}
-
-
@@ -1,24 +1,24 @@
+use crate::connectivity::DisjointSet;
+
+use super::trees::Tree;
+
+/// Tarjan's offline LCA, answering a batch of queries in one DFS
 pub struct TarjanLCA {
-    parent: Vec<usize>,      // For Union-Find
+    sets: DisjointSet,       // Subtrees merged into their finished ancestors
     ancestor: Vec<usize>,    // Marks visited ancestors
     visited: Vec<bool>,      // Tracks visited nodes
     queries: Vec<Vec<usize>>, // query[u] = list of v's to pair with u
-    answers: Vec<Option<usize>>, // Stores LCA(u, v) results
+    answers: Vec<(usize, usize, usize)>, // Stores (u, v, LCA(u, v)) results
 }
 
 impl TarjanLCA {
-    fn find(&mut self, u: usize) -> usize {
-        if self.parent[u] != u {
-            self.parent[u] = self.find(self.parent[u]); // Path compression
-        }
-        self.parent[u]
-    }
-
-    fn union(&mut self, u: usize, v: usize) {
-        let root_u = self.find(u);
-        let root_v = self.find(v);
-        if root_u != root_v {
-            self.parent[root_v] = root_u;
+    fn new(n: usize) -> Self {
+        Self {
+            sets: DisjointSet::new(n),
+            ancestor: (0..n).collect(),
+            visited: vec![false; n],
+            queries: vec![Vec::new(); n],
+            answers: Vec::new(),
         }
     }
 
@@ -26,16 +26,17 @@ impl TarjanLCA {
         self.ancestor[u] = u;
         for &child in &tree.nodes[u].children {
             self.tarjan_dfs(child, tree);
-            self.union(u, child);
-            self.ancestor[self.find(u)] = u;
+            self.sets.union(u, child);
+            let root = self.sets.find(u);
+            self.ancestor[root] = u;
         }
         self.visited[u] = true;
 
         // Answer all queries involving u
-        for &v in &self.queries[u] {
+        for v in std::mem::take(&mut self.queries[u]) {
             if self.visited[v] {
-                let lca = self.ancestor[self.find(v)];
-                self.answers.push((u, v, lca));
+                let root = self.sets.find(v);
+                self.answers.push((u, v, self.ancestor[root]));
             }
         }
     }
@@ -52,4 +53,4 @@ pub fn process_queries(tree: &Tree, query_pairs: &[(usize, usize)]) -> Vec<(usiz
 
     solver.tarjan_dfs(tree.root, tree);
     solver.answers
-}
\ No newline at end of file
+}
@@ -1,6 +1,6 @@
 
 use std::rc::Rc;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 /// Definition for a binary tree node.
 /// To apply this tree node to bidirectional algorithm, a child-to-parent map
@@ -24,17 +24,14 @@ impl TreeNode {
 }
 
 /// Definition for an arbitrary tree
-///
-use std::collections::HashMap;
-
 #[derive(Debug)]
 pub struct Employee {
-    id: i32,
-    children: Vec<Employee>,
+    pub id: i32,
+    pub children: Vec<Employee>,
 }
 
 impl Employee {
-    fn new(id: i32) -> Self {
+    pub fn new(id: i32) -> Self {
         Employee {
             id,
             children: Vec::new(),
@@ -69,8 +66,8 @@ pub struct ThreadedBinaryTreeNode<T> {
 }
 
 pub struct ArenaTree<T> {
-    nodes: Vec<Node<T>>,
-    root: Option<NodeId>,
+    pub nodes: Vec<Node<T>>,
+    pub root: Option<NodeId>,
 }
 
 pub struct Node<T> {
@@ -80,4 +77,47 @@ pub struct Node<T> {
     pub parent: Option<NodeId>,
 }
 
-type NodeId = usize;
+pub type NodeId = usize;
+
+/// Rooted tree stored as an arena of nodes with parent links and depths,
+/// the input of the LCA preprocessing structures in this chapter.
+pub struct Tree {
+    pub nodes: Vec<TreeVertex>,
+    pub root: NodeId,
+}
+
+pub struct TreeVertex {
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+    pub depth: usize,
+}
+
+impl Tree {
+    /// Build a tree from the parent of every node; the root has no parent.
+    /// Panics if there is no root.
+    pub fn from_parents(parents: &[Option<NodeId>]) -> Self {
+        let mut nodes: Vec<TreeVertex> = parents.iter()
+            .map(|&parent| TreeVertex { parent, children: Vec::new(), depth: 0 })
+            .collect();
+        let mut root = None;
+        for (node, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(p) => nodes[*p].children.push(node),
+                None => root = Some(node),
+            }
+        }
+        let root = root.expect("tree has no root");
+
+        // Assign depths top-down
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            for i in 0..nodes[node].children.len() {
+                let child = nodes[node].children[i];
+                nodes[child].depth = nodes[node].depth + 1;
+                stack.push(child);
+            }
+        }
+
+        Tree { nodes, root }
+    }
+}
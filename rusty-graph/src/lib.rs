@@ -0,0 +1,33 @@
+//! Rust implementations of the algorithms from
+//! *Practical Graph Algorithms: Problems, Solutions, and Implementation in Rust*.
+//!
+//! Each public module maps to a chapter directory of the book.
+
+#[path = "ch02-representations/mod.rs"]
+pub mod representations;
+#[path = "ch03-traversal/mod.rs"]
+pub mod traversal;
+#[path = "ch04-shortest-path/mod.rs"]
+pub mod shortest_path;
+#[path = "ch05-mst/mod.rs"]
+pub mod mst;
+#[path = "ch06-connectivity/mod.rs"]
+pub mod connectivity;
+#[path = "ch07-topological-sort/mod.rs"]
+pub mod topological_sort;
+#[path = "ch08-network-flow/mod.rs"]
+pub mod network_flow;
+#[path = "ch09-eulerian-hamiltonian/mod.rs"]
+pub mod eulerian_hamiltonian;
+#[path = "ch10-coloring/mod.rs"]
+pub mod coloring;
+#[path = "ch11-tsp/mod.rs"]
+pub mod tsp;
+#[path = "ch13-parallel/mod.rs"]
+pub mod parallel;
+#[path = "centrality/mod.rs"]
+pub mod centrality;
+#[path = "ch51-least-common-ancestor/mod.rs"]
+pub mod lca;
+#[path = "problems/mod.rs"]
+pub mod problems;
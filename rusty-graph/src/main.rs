@@ -1,10 +1,3 @@
-#[path = "ch02-representations/mod.rs"]
-mod ch02_representations;
-#[path = "problems/mod.rs"]
-mod problems;
-
-
-
 fn main() {
     println!("Hello, Welcome to Practical Graph Algorithm, The Rusty Graph.!");
-}
\ No newline at end of file
+}
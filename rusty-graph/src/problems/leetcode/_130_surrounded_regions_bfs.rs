@@ -1,5 +1,8 @@
 use std::collections::VecDeque;
-use solution::Solution;
+
+use crate::shortest_path::grid_neighbors;
+
+pub struct Solution;
 
 impl Solution {
     pub fn solve(board: &mut Vec<Vec<char>>) {
@@ -37,10 +40,7 @@ impl Solution {
 
             board[i][j] = 'T';
 
-            if i > 0 { queue.push_back((i - 1, j)); }
-            if j > 0 { queue.push_back((i, j - 1)); }
-            if i < rows - 1 { queue.push_back((i + 1, j)); }
-            if j < cols - 1 { queue.push_back((i, j + 1)); }
+            queue.extend(grid_neighbors(rows, cols, (i, j)));
         }
 
         // Final processing
@@ -54,4 +54,4 @@ impl Solution {
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use solution::Solution;
+pub struct Solution;
 
 impl Solution {
     pub fn solve(board: &mut Vec<Vec<char>>) {
@@ -44,4 +44,4 @@ impl Solution {
         if i < board.len() - 1 { Self::dfs(board, i + 1, j); }
         if j < board[0].len() - 1 { Self::dfs(board, i, j + 1); }
     }
-}
\ No newline at end of file
+}
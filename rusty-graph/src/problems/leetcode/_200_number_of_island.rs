@@ -1,4 +1,4 @@
-use solution::Solution;
+pub struct Solution;
 
 impl Solution {
     pub fn num_islands(grid: Vec<Vec<char>>) -> i32 {
@@ -33,4 +33,4 @@ impl Solution {
         if i < grid.len() - 1 { Self::dfs(grid, i + 1, j); }
         if j < grid[0].len() - 1 { Self::dfs(grid, i, j + 1); }
     }
-}
\ No newline at end of file
+}
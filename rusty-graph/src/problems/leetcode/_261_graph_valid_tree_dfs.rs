@@ -1,4 +1,4 @@
-use solution::Solution;
+pub struct Solution;
 
 impl Solution {
     pub fn valid_tree(n: i32, edges: Vec<Vec<i32>>) -> bool {
@@ -39,4 +39,4 @@ impl Solution {
         false
     }
 
-}
\ No newline at end of file
+}
@@ -1,4 +1,6 @@
-use solution::{Solution, UnionFind};
+use crate::connectivity::DisjointSet;
+
+pub struct Solution;
 
 impl Solution {
     pub fn valid_tree(n: i32, edges: Vec<Vec<i32>>) -> bool {
@@ -7,7 +9,7 @@ impl Solution {
             return false; // Must have exactly n-1 edges for a tree
         }
 
-        let mut uf = UnionFind::new(n);
+        let mut uf = DisjointSet::new(n);
         for edge in edges {
             let x = edge[0] as usize;
             let y = edge[1] as usize;
@@ -17,4 +19,4 @@ impl Solution {
         }
         true
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,8 @@
 use std::collections::VecDeque;
-use solution::Solution;
+
+use crate::shortest_path::grid_neighbors;
+
+pub struct Solution;
 
 impl Solution {
     pub fn walls_and_gates(rooms: &mut Vec<Vec<i32>>) {
@@ -20,25 +23,14 @@ impl Solution {
             }
         }
 
-        // Directions: up, down, left, right
-        let directions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
         while let Some((i, j)) = queue.pop_front() {
-            for (di, dj) in directions.iter() {
-                let ni = i as i32 + di;
-                let nj = j as i32 + dj;
-
-                // Check bounds and if it's an empty room
-                if ni >= 0 && ni < rows as i32 && nj >= 0 && nj < cols as i32 {
-                    let ni = ni as usize;
-                    let nj = nj as usize;
-
-                    if rooms[ni][nj] == i32::MAX {
-                        rooms[ni][nj] = rooms[i][j] + 1;
-                        queue.push_back((ni, nj));
-                    }
+            for (ni, nj) in grid_neighbors(rows, cols, (i, j)) {
+                // Only fill empty rooms
+                if rooms[ni][nj] == i32::MAX {
+                    rooms[ni][nj] = rooms[i][j] + 1;
+                    queue.push_back((ni, nj));
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
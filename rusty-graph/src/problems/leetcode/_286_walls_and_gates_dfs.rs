@@ -1,4 +1,4 @@
-use solution::Solution;
+pub struct Solution;
 
 impl Solution {
     pub fn walls_and_gates(rooms: &mut Vec<Vec<i32>>) {
@@ -30,4 +30,4 @@ impl Solution {
         if i < rooms.len() - 1 { Self::dfs(rooms, i + 1, j, distance + 1); }
         if j < rooms[0].len() - 1 { Self::dfs(rooms, i, j + 1, distance + 1); }
     }
-}
\ No newline at end of file
+}
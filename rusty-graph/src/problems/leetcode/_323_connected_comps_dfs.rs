@@ -1,4 +1,4 @@
-use solution::Solution;
+pub struct Solution;
 
 impl Solution {
     pub fn count_components(n: i32, edges: Vec<Vec<i32>>) -> i32 {
@@ -34,4 +34,4 @@ impl Solution {
             }
         }
     }
-}
\ No newline at end of file
+}
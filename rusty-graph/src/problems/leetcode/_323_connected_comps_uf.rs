@@ -1,9 +1,11 @@
-use solution::{Solution, UnionFind};
+use crate::connectivity::DisjointSet;
+
+pub struct Solution;
 
 impl Solution {
     pub fn count_components(n: i32, edges: Vec<Vec<i32>>) -> i32 {
         let n = n as usize;
-        let mut uf = UnionFind::new(n);
+        let mut uf = DisjointSet::new(n);
 
         for edge in edges {
             let x = edge[0] as usize;
@@ -11,6 +13,6 @@ impl Solution {
             uf.union(x, y);
         }
 
-        uf.count as i32
+        uf.count() as i32
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use solution::Solution;
+pub struct Solution;
 
 impl Solution {
     pub fn count_battleships(board: Vec<Vec<char>>) -> i32 {
@@ -16,4 +16,4 @@ impl Solution {
         }
         count
     }
-}
\ No newline at end of file
+}
@@ -1,3 +1,5 @@
+pub struct Solution;
+
 impl Solution {
     pub fn count_battleships(board: Vec<Vec<char>>) -> i32 {
         let mut board = board;
@@ -24,4 +26,4 @@ impl Solution {
         Self::dfs(board, i + 1, j);
         Self::dfs(board, i, j + 1);
     }
-}
\ No newline at end of file
+}
@@ -1,3 +1,5 @@
+pub struct Solution;
+
 impl Solution {
     pub fn find_circle_num(is_connected: Vec<Vec<i32>>) -> i32 {
         let n = is_connected.len();
@@ -23,4 +25,4 @@ impl Solution {
         }
     }
 
-}
\ No newline at end of file
+}
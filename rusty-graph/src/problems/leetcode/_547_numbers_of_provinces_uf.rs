@@ -1,7 +1,11 @@
+use crate::connectivity::DisjointSet;
+
+pub struct Solution;
+
 impl Solution {
     pub fn find_circle_num(is_connected: Vec<Vec<i32>>) -> i32 {
         let n = is_connected.len();
-        let mut uf = UnionFind::new(n);
+        let mut uf = DisjointSet::new(n);
 
         for i in 0..n {
             for j in 0..n {
@@ -11,6 +15,6 @@ impl Solution {
             }
         }
 
-        uf.count as i32
+        uf.count() as i32
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,9 @@
 use std::collections::VecDeque;
 
+use crate::shortest_path::grid_neighbors;
+
+pub struct Solution;
+
 impl Solution {
     pub fn oranges_rotting(mut grid: Vec<Vec<i32>>) -> i32 {
         let rows = grid.len();
@@ -20,29 +24,20 @@ impl Solution {
             }
         }
 
-        let directions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
         let mut minutes = 0;
 
         while let Some((i, j, time)) = queue.pop_front() {
             minutes = time;
 
-            for (di, dj) in directions.iter() {
-                let ni = i as i32 + di;
-                let nj = j as i32 + dj;
-
-                if ni >= 0 && ni < rows as i32 && nj >= 0 && nj < cols as i32 {
-                    let ni = ni as usize;
-                    let nj = nj as usize;
-
-                    if grid[ni][nj] == 1 {
-                        grid[ni][nj] = 2;
-                        fresh -= 1;
-                        queue.push_back((ni, nj, time + 1));
-                    }
+            for (ni, nj) in grid_neighbors(rows, cols, (i, j)) {
+                if grid[ni][nj] == 1 {
+                    grid[ni][nj] = 2;
+                    fresh -= 1;
+                    queue.push_back((ni, nj, time + 1));
                 }
             }
         }
 
         if fresh == 0 { minutes } else { -1 }
     }
-}
\ No newline at end of file
+}
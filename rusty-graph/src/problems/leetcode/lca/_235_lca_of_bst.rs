@@ -1,4 +1,7 @@
-use solution::Solution;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::super::trees::TreeNode;
 
 // Iterative Solution (Optimal for BST)
 pub fn lowest_common_ancestor_iterative(
@@ -22,30 +25,3 @@ pub fn lowest_common_ancestor_iterative(
     }
     None
 }
-
-fn main() {
-    // Example BST: [6,2,8,0,4,7,9,null,null,3,5]
-    let root = Some(Rc::new(RefCell::new(TreeNode {
-        val: 6,
-        left: Some(Rc::new(RefCell::new(TreeNode {
-            val: 2,
-            left: Some(Rc::new(RefCell::new(TreeNode::new(0)))),
-            right: Some(Rc::new(RefCell::new(TreeNode {
-                val: 4,
-                left: Some(Rc::new(RefCell::new(TreeNode::new(3)))),
-                right: Some(Rc::new(RefCell::new(TreeNode::new(5)))),
-            }))),
-        }))),
-        right: Some(Rc::new(RefCell::new(TreeNode {
-            val: 8,
-            left: Some(Rc::new(RefCell::new(TreeNode::new(7)))),
-            right: Some(Rc::new(RefCell::new(TreeNode::new(9)))),
-        }))),
-    })));
-
-    let p = Some(Rc::new(RefCell::new(TreeNode::new(2)))); // Node 2
-    let q = Some(Rc::new(RefCell::new(TreeNode::new(8)))); // Node 8
-
-    let lca = lowest_common_ancestor_iterative(root.clone(), p, q);
-    println!("LCA: {}", lca.unwrap().borrow().val); // Output: 6
-}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod _235_lca_of_bst;
@@ -0,0 +1,22 @@
+// Solutions keep LeetCode's signatures and index grids by row and column
+#![allow(clippy::needless_range_loop, clippy::ptr_arg)]
+
+pub mod trees;
+pub mod lca;
+
+pub mod _130_surrounded_regions_bfs;
+pub mod _130_surrounded_regions_dfs;
+pub mod _200_number_of_island;
+pub mod _261_graph_valid_tree_dfs;
+pub mod _261_graph_valid_tree_uf;
+pub mod _286_walls_and_gates_bfs;
+pub mod _286_walls_and_gates_dfs;
+pub mod _305_number_of_islands_2;
+pub mod _323_connected_comps_dfs;
+pub mod _323_connected_comps_uf;
+pub mod _419_battleships_in_board_bfs;
+pub mod _419_battleships_in_board_dfs;
+pub mod _547_numbers_of_provinces_dfs;
+pub mod _547_numbers_of_provinces_uf;
+pub mod _994_rotting_oranges;
+pub mod _994_rotting_oranges_mut;
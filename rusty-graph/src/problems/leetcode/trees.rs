@@ -0,0 +1 @@
+pub use crate::lca::trees::TreeNode;